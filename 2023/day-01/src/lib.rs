@@ -0,0 +1,125 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::Solution;
+
+const RADIX: u32 = 10;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(input: &String) -> Result<impl Display> {
+        let mut sum = 0;
+
+        for s in input.lines() {
+            let digits: Vec<char> = s.chars().filter(|c| c.is_numeric()).collect();
+
+            let first = digits.first().unwrap().to_digit(RADIX).unwrap();
+            let last = digits.last().unwrap().to_digit(RADIX).unwrap();
+            sum = sum + first * 10 + last;
+        }
+
+        Ok(sum)
+    }
+
+    fn part_two(input: &String) -> Result<impl Display> {
+        let mut sum = 0;
+
+        for s in input.lines() {
+            let first = first_digit(s).unwrap();
+            let last = last_digit(s).unwrap();
+
+            sum = sum + first * 10 + last;
+        }
+
+        Ok(sum)
+    }
+}
+
+fn first_digit(s: &str) -> Option<u32> {
+    match s.chars().next() {
+        None => return None,
+        Some(c) => {
+            if c.is_numeric() {
+                return c.to_digit(RADIX);
+            }
+        }
+    }
+
+    if s.starts_with("one") {
+        return Some(1);
+    }
+    if s.starts_with("two") {
+        return Some(2);
+    }
+    if s.starts_with("three") {
+        return Some(3);
+    }
+    if s.starts_with("four") {
+        return Some(4);
+    }
+    if s.starts_with("five") {
+        return Some(5);
+    }
+    if s.starts_with("six") {
+        return Some(6);
+    }
+    if s.starts_with("seven") {
+        return Some(7);
+    }
+    if s.starts_with("eight") {
+        return Some(8);
+    }
+    if s.starts_with("nine") {
+        return Some(9);
+    }
+
+    first_digit(&s[1..])
+}
+
+fn last_digit(s: &str) -> Option<u32> {
+    match s.chars().next_back() {
+        None => return None,
+        Some(c) => {
+            if c.is_numeric() {
+                return c.to_digit(RADIX);
+            }
+        }
+    }
+
+    if s.ends_with("one") {
+        return Some(1);
+    }
+    if s.ends_with("two") {
+        return Some(2);
+    }
+    if s.ends_with("three") {
+        return Some(3);
+    }
+    if s.ends_with("four") {
+        return Some(4);
+    }
+    if s.ends_with("five") {
+        return Some(5);
+    }
+    if s.ends_with("six") {
+        return Some(6);
+    }
+    if s.ends_with("seven") {
+        return Some(7);
+    }
+    if s.ends_with("eight") {
+        return Some(8);
+    }
+    if s.ends_with("nine") {
+        return Some(9);
+    }
+
+    last_digit(&s[..s.len() - 1])
+}
@@ -1,10 +1,7 @@
-use lazy_static::lazy_static;
-use regex::Regex;
 use std::cmp;
 
-lazy_static! {
-    static ref DRAW_REGEX: Regex = Regex::new(r"(?<count>\d+) (?<color>\w+)").unwrap();
-}
+use anyhow::Result;
+use common::Parser;
 
 pub struct Draw {
     pub red: u32,
@@ -13,34 +10,30 @@ pub struct Draw {
 }
 
 impl Draw {
-    pub fn parse(line: &str) -> Draw {
-        let mut draw = Draw {
+    /// Parses a draw such as `3 blue, 4 red`.
+    pub fn parse(parser: &mut Parser) -> Result<Draw> {
+        let mut result = Draw {
             blue: 0,
             red: 0,
             green: 0,
         };
 
-        line.split(", ")
-            .map(|count| DRAW_REGEX.captures(count).unwrap())
-            .map(|capture| {
-                (
-                    capture
-                        .name("count")
-                        .unwrap()
-                        .as_str()
-                        .parse::<u32>()
-                        .unwrap(),
-                    capture.name("color").unwrap().as_str(),
-                )
-            })
-            .for_each(|(count, color)| match color {
-                "blue" => draw.blue = count,
-                "red" => draw.red = count,
-                "green" => draw.green = count,
-                color => panic!("Unknown color {}", color),
-            });
-
-        draw
+        parser.separated(", ", |parser| {
+            let count = parser.number()?;
+            parser.literal(" ")?;
+            let start = parser.clone();
+
+            match parser.take_while(|c| c.is_ascii_alphabetic()) {
+                "blue" => result.blue = count,
+                "red" => result.red = count,
+                "green" => result.green = count,
+                color => return Err(start.error(format!("Unknown color {:?}", color))),
+            }
+
+            Ok(())
+        })?;
+
+        Ok(result)
     }
 
     pub fn allows(&self, other: &Draw) -> bool {
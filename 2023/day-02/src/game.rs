@@ -1,31 +1,22 @@
-use lazy_static::lazy_static;
-use regex::Regex;
+use anyhow::Result;
+use common::Parser;
 
 use crate::draw::Draw;
 
-lazy_static! {
-    static ref GAME_REGEX: Regex = Regex::new(r"^Game (?<id>\d+): (?<draws>.*)").unwrap();
-}
-
 pub struct Game {
     pub id: u32,
     pub draws: Vec<Draw>,
 }
 
 impl Game {
-    pub fn parse(line: &str) -> Game {
-        let capture = GAME_REGEX.captures(line).expect("Failed to parse game");
-
-        let id = capture.name("id").unwrap().as_str().parse::<u32>().unwrap();
-        let draws = capture
-            .name("draws")
-            .unwrap()
-            .as_str()
-            .split("; ")
-            .map(|draw| Draw::parse(draw))
-            .collect();
+    /// Parses a line such as `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+    pub fn parse(parser: &mut Parser) -> Result<Game> {
+        parser.literal("Game ")?;
+        let id = parser.number()?;
+        parser.literal(": ")?;
+        let draws = parser.separated("; ", Draw::parse)?;
 
-        Game { id, draws }
+        Ok(Game { id, draws })
     }
 
     pub fn min_bag(&self) -> Draw {
@@ -0,0 +1,43 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::{Parser, Solution};
+use draw::Draw;
+use game::Game;
+
+mod draw;
+mod game;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Vec<Game>> {
+        let mut parser = Parser::new(input);
+        let games = parser.lines(Game::parse)?;
+        parser.end()?;
+
+        Ok(games)
+    }
+
+    fn part_one(games: &Vec<Game>) -> Result<impl Display> {
+        let bag = Draw {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        Ok(games
+            .iter()
+            .filter(|game| game.draws.iter().all(|draw| bag.allows(draw)))
+            .fold(0, |sum, game| sum + game.id))
+    }
+
+    fn part_two(games: &Vec<Game>) -> Result<impl Display> {
+        Ok(games
+            .iter()
+            .map(|game| game.min_bag())
+            .fold(0, |sum, bag| sum + bag.power()))
+    }
+}
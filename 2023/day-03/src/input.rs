@@ -1,7 +1,5 @@
-use std::{fs::File, io::BufReader};
-use utf8_chars::BufReadCharsExt;
-
-use crate::parser::{Location, Parser};
+use anyhow::Result;
+use common::{Location, Parser};
 
 pub struct Number {
     pub start: Location,
@@ -19,51 +17,37 @@ pub struct Input {
     pub symbols: Vec<Symbol>,
 }
 
-pub fn parse(filename: &str) -> Input {
-    let file = File::open(filename).expect("Failed to open input file");
-    let mut reader = BufReader::new(file);
-    let iter = reader.chars().map(|c| c.unwrap());
-
-    let mut parser = Parser::new(iter);
+pub fn parse(input: &str) -> Result<Input> {
+    let mut parser = Parser::new(input);
     let mut result = Input {
         numbers: Vec::new(),
         symbols: Vec::new(),
     };
 
-    loop {
-        loop {
-            match parser.peek() {
-                Some('0'..='9') => break,
-                Some('.' | '\n') => parser.consume(),
-                Some(value) => {
-                    result.symbols.push(Symbol {
-                        loc: parser.loc(),
-                        value,
-                    });
-                    parser.consume();
-                }
-                None => return result,
+    while let Some(c) = parser.peek() {
+        match c {
+            '0'..='9' => {
+                let start = parser.loc();
+                let value = parser.number()?;
+
+                result.numbers.push(Number {
+                    start,
+                    end: parser.loc(),
+                    value,
+                });
             }
-        }
-
-        let start = parser.loc();
-        let mut value = 0;
-
-        loop {
-            match parser.peek() {
-                Some(d @ '0'..='9') => {
-                    value = value * 10 + d.to_digit(10).unwrap();
-                    parser.consume();
-                }
-                _ => {
-                    result.numbers.push(Number {
-                        start,
-                        end: parser.loc(),
-                        value,
-                    });
-                    break;
-                }
+            '.' | '\r' | '\n' => {
+                parser.consume();
+            }
+            value => {
+                result.symbols.push(Symbol {
+                    loc: parser.loc(),
+                    value,
+                });
+                parser.consume();
             }
         }
     }
+
+    Ok(result)
 }
@@ -0,0 +1,66 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::Solution;
+use input::{Input, Number, Symbol};
+
+mod input;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        input::parse(input)
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(input
+            .numbers
+            .iter()
+            .filter(|number| {
+                input
+                    .symbols
+                    .iter()
+                    .any(|symbol| is_adjacent(number, symbol))
+            })
+            .map(|number| number.value)
+            .sum::<u32>())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Ok(input
+            .symbols
+            .iter()
+            .filter(|symbol| symbol.value == '*')
+            .map(|symbol| {
+                input
+                    .numbers
+                    .iter()
+                    .filter(|number| is_adjacent(number, symbol))
+                    .take(3)
+            })
+            .map(|t| t.collect())
+            .filter(|t: &Vec<&Number>| t.len() == 2)
+            .map(|t| t.iter().map(|n| n.value).product::<u32>())
+            .sum::<u32>())
+    }
+}
+
+fn is_adjacent(number: &Number, symbol: &Symbol) -> bool {
+    if symbol.loc.column > number.end.column || symbol.loc.line > number.start.line + 1 {
+        return false;
+    }
+
+    let line_min = match number.start.line {
+        0 => 0,
+        l => l - 1,
+    };
+    let column_min = match number.start.column {
+        0 => 0,
+        c => c - 1,
+    };
+
+    line_min <= symbol.loc.line && column_min <= symbol.loc.column
+}
@@ -1,8 +1,7 @@
-use std::{
-    collections::HashSet,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::collections::HashSet;
+
+use anyhow::Result;
+use common::Parser;
 
 pub struct Input {
     pub cards: Vec<Card>,
@@ -14,36 +13,28 @@ pub struct Card {
     pub your_numbers: HashSet<u32>,
 }
 
-pub fn parse(filename: &str) -> Input {
-    let file = File::open(filename).expect("Failed to open input file");
-    let cards = BufReader::new(file)
-        .lines()
-        .map(|l| l.unwrap())
-        .map(|l| parse_card(&l))
-        .collect();
+pub fn parse(contents: &str) -> Result<Input> {
+    let mut parser = Parser::new(contents);
+    let cards = parser.lines(parse_card)?;
+    parser.end()?;
 
-    Input { cards }
+    Ok(Input { cards })
 }
 
-fn parse_card(line: &str) -> Card {
-    let (card, numbers) = line.split_once(": ").unwrap();
-    let number = card
-        .split_whitespace()
-        .collect::<Vec<&str>>()
-        .get(1)
-        .map(|id| id.parse::<u32>().unwrap())
-        .unwrap();
-    let (winning, yours) = numbers.split_once(" | ").unwrap();
+/// Parses a line such as `Card 1: 41 48 | 83 86 48`.
+fn parse_card(parser: &mut Parser) -> Result<Card> {
+    parser.literal("Card")?;
+    parser.spaces();
+    let number = parser.number()?;
+    parser.literal(":")?;
+    let winning_numbers = parser.numbers()?.into_iter().collect();
+    parser.spaces();
+    parser.literal("|")?;
+    let your_numbers = parser.numbers()?.into_iter().collect();
 
-    Card {
+    Ok(Card {
         number,
-        winning_numbers: HashSet::from_iter(
-            winning
-                .split_whitespace()
-                .map(|n| n.parse::<u32>().unwrap()),
-        ),
-        your_numbers: HashSet::from_iter(
-            yours.split_whitespace().map(|n| n.parse::<u32>().unwrap()),
-        ),
-    }
+        winning_numbers,
+        your_numbers,
+    })
 }
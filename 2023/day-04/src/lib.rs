@@ -0,0 +1,56 @@
+use std::cmp;
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::Solution;
+use input::Input;
+
+mod input;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        input::parse(input)
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(input
+            .cards
+            .iter()
+            .map(|card| card.winning_numbers.intersection(&card.your_numbers))
+            .map(|iter| iter.count())
+            .map(|num_winning| match num_winning {
+                0 => 0,
+                n => 2_u32.pow((n as u32) - 1),
+            })
+            .sum::<u32>())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        let len = input.cards.len();
+        let mut counts = vec![1; len];
+
+        input
+            .cards
+            .iter()
+            .map(|card| {
+                card.winning_numbers
+                    .intersection(&card.your_numbers)
+                    .count()
+            })
+            .enumerate()
+            .for_each(|(index, count)| {
+                let start = cmp::min(index + 1, len);
+                let end = cmp::min(index + count + 1, len);
+
+                for i in start..end {
+                    counts[i] += counts[index];
+                }
+            });
+
+        Ok(counts.iter().sum::<u32>())
+    }
+}
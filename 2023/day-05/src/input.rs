@@ -1,8 +1,7 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    ops::Range,
-};
+use std::ops::Range;
+
+use anyhow::Result;
+use common::Parser;
 
 pub struct Input {
     pub seeds: Vec<u64>,
@@ -37,119 +36,76 @@ pub struct RangeMap {
     pub dest: Range<u64>,
 }
 
-pub fn parse_input(filename: &str) -> Input {
-    let file = File::open(filename).expect("Failed to open input file");
-    let mut lines = BufReader::new(file).lines().map(|line| line.unwrap());
+pub fn parse_input(contents: &str) -> Result<Input> {
+    let mut parser = Parser::new(contents);
     let mut input = Input::new();
 
-    loop {
-        match lines.next() {
-            None => return input,
-            Some(line) => {
-                let colon = line.find(":").unwrap();
-
-                match &line[0..colon] {
-                    "seeds" => parse_list(
-                        &mut input.seeds,
-                        &mut input.seed_ranges,
-                        &line[(colon + 1)..],
-                        &mut lines,
-                    ),
-                    "seed-to-soil map" => parse_map(&mut input.seed_to_soil, &mut lines),
-                    "soil-to-fertilizer map" => {
-                        parse_map(&mut input.soil_to_fertilizer, &mut lines)
-                    }
-                    "fertilizer-to-water map" => {
-                        parse_map(&mut input.fertilizer_to_water, &mut lines)
-                    }
-                    "water-to-light map" => parse_map(&mut input.water_to_light, &mut lines),
-                    "light-to-temperature map" => {
-                        parse_map(&mut input.light_to_temperature, &mut lines)
-                    }
-                    "temperature-to-humidity map" => {
-                        parse_map(&mut input.temperature_to_humidity, &mut lines)
-                    }
-                    "humidity-to-location map" => {
-                        parse_map(&mut input.humidity_to_location, &mut lines)
-                    }
+    while let Some(mut section) = parser.section() {
+        let start = section.clone();
+        let name = section.take_while(|c| c != ':');
+        section.literal(":")?;
 
-                    _ => panic!("Unknown section {}", line),
-                }
+        let ranges = match name {
+            "seeds" => {
+                parse_seeds(&mut input, &mut section)?;
+                continue;
             }
-        }
+            "seed-to-soil map" => &mut input.seed_to_soil,
+            "soil-to-fertilizer map" => &mut input.soil_to_fertilizer,
+            "fertilizer-to-water map" => &mut input.fertilizer_to_water,
+            "water-to-light map" => &mut input.water_to_light,
+            "light-to-temperature map" => &mut input.light_to_temperature,
+            "temperature-to-humidity map" => &mut input.temperature_to_humidity,
+            "humidity-to-location map" => &mut input.humidity_to_location,
+            _ => return Err(start.error(format!("Unknown section {:?}", name))),
+        };
+
+        section.newline()?;
+        *ranges = section.lines(parse_range_map)?;
+        ranges.sort_unstable_by_key(|a| a.source.start);
     }
-}
 
-fn parse_list(
-    seeds: &mut Vec<u64>,
-    seed_ranges: &mut Vec<Range<u64>>,
-    line: &str,
-    lines: &mut std::iter::Map<
-        std::io::Lines<BufReader<File>>,
-        impl FnMut(Result<String, std::io::Error>) -> String,
-    >,
-) {
-    line.split_whitespace()
-        .map(|id_str| id_str.parse::<u64>().unwrap())
-        .for_each(|id| seeds.push(id));
-
-    let mut nums = line
-        .split_whitespace()
-        .map(|id_str| id_str.parse::<u64>().unwrap());
+    Ok(input)
+}
 
-    loop {
-        let start = nums.next();
-        if start.is_none() {
-            break;
-        }
+/// The `seeds:` line, read both as single seeds and as start/length pairs.
+fn parse_seeds(input: &mut Input, parser: &mut Parser) -> Result<()> {
+    let start = parser.clone();
+    input.seeds = parser.numbers()?;
+    parser.end()?;
 
-        let start = start.unwrap();
-        let length = nums.next().unwrap();
-        seed_ranges.push(Range {
-            start,
-            end: start + length,
-        })
+    if !input.seeds.len().is_multiple_of(2) {
+        return Err(start.error("Expected seeds to come in start/length pairs"));
     }
 
-    seed_ranges.sort_by(|a, b| a.start.cmp(&b.start));
+    input.seed_ranges = input
+        .seeds
+        .chunks(2)
+        .map(|pair| Range {
+            start: pair[0],
+            end: pair[0] + pair[1],
+        })
+        .collect();
+    input.seed_ranges.sort_by_key(|a| a.start);
 
-    lines.next();
+    Ok(())
 }
 
-fn parse_map(
-    ranges: &mut Vec<RangeMap>,
-    lines: &mut std::iter::Map<
-        std::io::Lines<BufReader<File>>,
-        impl FnMut(Result<String, std::io::Error>) -> String,
-    >,
-) {
-    loop {
-        match lines.next() {
-            None => break,
-            Some(line) if line.len() == 0 => break,
-            Some(line) => {
-                let digits: Vec<u64> = line
-                    .split_whitespace()
-                    .map(|id_str| id_str.parse::<u64>().unwrap())
-                    .collect();
-
-                assert_eq!(digits.len(), 3);
-
-                let (dest_start, source_start, length) = (digits[0], digits[1], digits[2]);
-
-                ranges.push(RangeMap {
-                    source: Range {
-                        start: source_start,
-                        end: source_start + length,
-                    },
-                    dest: Range {
-                        start: dest_start,
-                        end: dest_start + length,
-                    },
-                });
-            }
-        }
-    }
-
-    ranges.sort_unstable_by(|a, b| a.source.start.cmp(&b.source.start));
+/// A line such as `50 98 2`: destination start, source start, then length.
+fn parse_range_map(parser: &mut Parser) -> Result<RangeMap> {
+    let start = parser.clone();
+    let [dest_start, source_start, length] = parser.numbers()?[..] else {
+        return Err(start.error("Expected 3 numbers"));
+    };
+
+    Ok(RangeMap {
+        source: Range {
+            start: source_start,
+            end: source_start + length,
+        },
+        dest: Range {
+            start: dest_start,
+            end: dest_start + length,
+        },
+    })
 }
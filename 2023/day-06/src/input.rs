@@ -1,53 +1,53 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use anyhow::{bail, Context, Result};
+use common::Parser;
 
 use crate::race::Race;
 
-pub struct InputOne {
+pub struct Input {
+    /// One race per column, as read for part one.
     pub races: Vec<Race>,
+    /// The single race formed by ignoring the spaces, for part two.
+    pub race: Race,
 }
 
-pub fn parse_input_one(filename: &str) -> InputOne {
-    let file = File::open(filename).expect("Failed to open input file");
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines();
-
-    let time = &lines.next().unwrap().unwrap()[10..];
-    let time = time
-        .split_ascii_whitespace()
-        .map(|str| str.parse::<u64>().unwrap());
-
-    let distance = &lines.next().unwrap().unwrap()[10..];
-    let distance = distance
-        .split_ascii_whitespace()
-        .map(|str| str.parse::<u64>().unwrap());
+pub fn parse_input(contents: &str) -> Result<Input> {
+    let mut parser = Parser::new(contents);
+    let time = parse_numbers(&mut parser, "Time:")?;
+    parser.newline()?;
+    let distance = parse_numbers(&mut parser, "Distance:")?;
+    parser.end()?;
+
+    if time.len() != distance.len() {
+        bail!(
+            "Found {} times but {} distances",
+            time.len(),
+            distance.len()
+        );
+    }
 
-    InputOne {
+    Ok(Input {
+        race: Race {
+            time: join_digits(&time)?,
+            distance: join_digits(&distance)?,
+        },
         races: time
+            .into_iter()
             .zip(distance)
             .map(|(time, distance)| Race { time, distance })
             .collect(),
-    }
+    })
 }
 
-pub fn parse_input_two(filename: &str) -> Race {
-    let file = File::open(filename).expect("Failed to open input file");
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines();
-
-    let time = parse_digits(lines.next().unwrap().unwrap());
-    let distance = parse_digits(lines.next().unwrap().unwrap());
-
-    Race { time, distance }
+fn parse_numbers(parser: &mut Parser, label: &str) -> Result<Vec<u64>> {
+    parser.literal(label)?;
+    parser.numbers()
 }
 
-fn parse_digits(input: String) -> u64 {
-    input
-        .chars()
-        .filter(|f| f.is_digit(10))
-        .collect::<String>()
-        .parse::<u64>()
-        .unwrap()
+/// The number written by running `numbers` together without their spaces.
+fn join_digits(numbers: &[u64]) -> Result<u64> {
+    let digits: String = numbers.iter().map(u64::to_string).collect();
+
+    digits
+        .parse()
+        .with_context(|| format!("{} is too big for a race", digits))
 }
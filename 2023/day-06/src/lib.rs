@@ -0,0 +1,26 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::Solution;
+use input::Input;
+
+mod input;
+mod race;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        input::parse_input(input)
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(input.races.iter().map(|r| r.ways_to_win()).product::<u64>())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Ok(input.race.ways_to_win())
+    }
+}
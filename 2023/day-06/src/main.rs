@@ -1,23 +1,6 @@
-use input::parse_input_two;
+use anyhow::Result;
+use aoc_2023_day_06::Day06;
 
-use crate::input::parse_input_one;
-
-mod input;
-mod race;
-
-fn main() {
-    println!("Part one: {}", part_one());
-    println!("Part two: {}", part_two());
-}
-
-fn part_one() -> u64 {
-    parse_input_one("input.txt")
-        .races
-        .iter()
-        .map(|r| r.ways_to_win())
-        .product()
-}
-
-fn part_two() -> u64 {
-    parse_input_two("input.txt").ways_to_win()
+fn main() -> Result<()> {
+    common::main::<Day06>(2023, 6)
 }
@@ -10,16 +10,17 @@ pub enum Card {
     Eight = 8,
     Nine = 9,
     Ten = 10,
-    // Jack = 11,
+    Jack = 11,
     Queen = 12,
     King = 13,
     Ace = 14,
 }
 
 impl Card {
-    pub fn parse(c: char) -> Card {
-        match c {
-            'J' => Card::Joker,
+    /// With `jokers`, a `J` is the weakest wildcard rather than a Jack.
+    pub fn parse(c: char, jokers: bool) -> Option<Card> {
+        Some(match c {
+            'J' if jokers => Card::Joker,
             '2' => Card::Two,
             '3' => Card::Three,
             '4' => Card::Four,
@@ -29,11 +30,11 @@ impl Card {
             '8' => Card::Eight,
             '9' => Card::Nine,
             'T' => Card::Ten,
-            // 'J' => Card::Jack,
+            'J' => Card::Jack,
             'Q' => Card::Queen,
             'K' => Card::King,
             'A' => Card::Ace,
-            _ => panic!("Unknown card"),
-        }
+            _ => return None,
+        })
     }
 }
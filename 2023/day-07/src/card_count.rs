@@ -18,7 +18,7 @@ pub struct CardCount {
     pub eight: u8,
     pub nine: u8,
     pub ten: u8,
-    // pub jack: u8,
+    pub jack: u8,
     pub queen: u8,
     pub king: u8,
     pub ace: u8,
@@ -42,7 +42,7 @@ impl CardCount {
             eight: 0,
             nine: 0,
             ten: 0,
-            // jack: 0,
+            jack: 0,
             queen: 0,
             king: 0,
             ace: 0,
@@ -55,65 +55,64 @@ impl CardCount {
     }
 
     pub fn add_card(mut self, card: &Card) -> Self {
-        let new_count;
-        match card {
+        let new_count = match card {
             Card::Joker => {
                 self.joker += 1;
-                new_count = self.joker;
+                self.joker
             }
             Card::Two => {
                 self.two += 1;
-                new_count = self.two;
+                self.two
             }
             Card::Three => {
                 self.three += 1;
-                new_count = self.three;
+                self.three
             }
             Card::Four => {
                 self.four += 1;
-                new_count = self.four;
+                self.four
             }
             Card::Five => {
                 self.five += 1;
-                new_count = self.five;
+                self.five
             }
             Card::Six => {
                 self.six += 1;
-                new_count = self.six;
+                self.six
             }
             Card::Seven => {
                 self.seven += 1;
-                new_count = self.seven;
+                self.seven
             }
             Card::Eight => {
                 self.eight += 1;
-                new_count = self.eight;
+                self.eight
             }
             Card::Nine => {
                 self.nine += 1;
-                new_count = self.nine;
+                self.nine
             }
             Card::Ten => {
                 self.ten += 1;
-                new_count = self.ten;
+                self.ten
+            }
+            Card::Jack => {
+                self.jack += 1;
+                self.jack
             }
-            // Card::Jack => {
-            //     self.jack += 1;
-            //     new_count = self.jack;
-            // }
             Card::Queen => {
                 self.queen += 1;
-                new_count = self.queen;
+                self.queen
             }
             Card::King => {
                 self.king += 1;
-                new_count = self.king;
+                self.king
             }
             Card::Ace => {
                 self.ace += 1;
-                new_count = self.ace;
+                self.ace
             }
-        }
+        };
 
         match new_count {
             1 => self.singles += 1,
@@ -1,11 +1,27 @@
+use anyhow::Result;
+use common::Parser;
+
 use crate::card::Card;
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct Cards(pub Card, pub Card, pub Card, pub Card, pub Card);
 
 impl Cards {
-    pub fn parse(line: &str) -> Cards {
-        line.chars().map(|c| Card::parse(c)).collect::<Cards>()
+    /// Parses exactly five cards.
+    pub fn parse(parser: &mut Parser, jokers: bool) -> Result<Cards> {
+        let mut cards = Vec::new();
+
+        for _ in 0..5 {
+            let start = parser.clone();
+            let card = parser.consume().and_then(|c| Card::parse(c, jokers));
+
+            match card {
+                Some(card) => cards.push(card),
+                None => return Err(start.error("Expected a card")),
+            }
+        }
+
+        Ok(cards.into_iter().collect())
     }
 }
 
@@ -1,3 +1,6 @@
+use anyhow::Result;
+use common::Parser;
+
 use crate::{cards::Cards, hand_type::HandType};
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
@@ -8,13 +11,15 @@ pub struct Hand {
 }
 
 impl Hand {
-    pub fn parse(line: String) -> Hand {
-        let cards = Cards::parse(&line[..5]);
+    /// Parses a line such as `32T3K 765`.
+    pub fn parse(parser: &mut Parser, jokers: bool) -> Result<Hand> {
+        let cards = Cards::parse(parser, jokers)?;
+        parser.literal(" ")?;
 
-        Hand {
+        Ok(Hand {
             hand_type: HandType::compute(&cards),
             cards,
-            bid: line[6..].parse::<u32>().unwrap(),
-        }
+            bid: parser.number()?,
+        })
     }
 }
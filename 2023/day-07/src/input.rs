@@ -1,7 +1,5 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use anyhow::Result;
+use common::Parser;
 
 use crate::hand::Hand;
 
@@ -12,15 +10,22 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(filename: &str) -> Input {
-        let file = File::open(filename).expect("Failed to open input file");
-        let mut hands: Vec<Hand> = BufReader::new(file)
-            .lines()
-            .map(|line| Hand::parse(line.unwrap()))
-            .collect();
+    /// Parses every hand, reading `J` as a Joker when `jokers` is set, and sorts them by rank.
+    pub fn parse(contents: &str, jokers: bool) -> Result<Input> {
+        let mut parser = Parser::new(contents);
+        let mut hands = parser.lines(|line| Hand::parse(line, jokers))?;
+        parser.end()?;
 
         hands.sort();
 
-        Input { hands }
+        Ok(Input { hands })
+    }
+
+    pub fn winnings(&self) -> u32 {
+        self.hands
+            .iter()
+            .enumerate()
+            .map(|(i, h)| (i as u32 + 1) * h.bid)
+            .sum()
     }
 }
@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::Solution;
+use input::Input;
+
+mod card;
+mod card_count;
+mod cards;
+mod hand;
+mod hand_type;
+mod input;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = (Input, Input);
+
+    fn parse(input: &str) -> Result<(Input, Input)> {
+        Ok((Input::parse(input, false)?, Input::parse(input, true)?))
+    }
+
+    fn part_one((jacks, _): &(Input, Input)) -> Result<impl Display> {
+        Ok(jacks.winnings())
+    }
+
+    fn part_two((_, jokers): &(Input, Input)) -> Result<impl Display> {
+        Ok(jokers.winnings())
+    }
+}
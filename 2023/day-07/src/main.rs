@@ -1,19 +1,6 @@
-use crate::input::Input;
+use anyhow::Result;
+use aoc_2023_day_07::Day07;
 
-mod card;
-mod card_count;
-mod cards;
-mod hand;
-mod hand_type;
-mod input;
-
-fn main() {
-    let value: u32 = Input::parse("input.txt")
-        .hands
-        .iter()
-        .enumerate()
-        .map(|(i, h)| (i as u32 + 1) * h.bid)
-        .sum();
-
-    println!("Value: {}", value);
+fn main() -> Result<()> {
+    common::main::<Day07>(2023, 7)
 }
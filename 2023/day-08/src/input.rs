@@ -1,8 +1,7 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::collections::HashMap;
+
+use anyhow::Result;
+use common::Parser;
 
 pub enum Direction {
     Left,
@@ -17,37 +16,56 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn parse(filename: &str) -> Input {
-        let file = File::open(filename).expect("Failed to open input file");
-        let reader = BufReader::new(file);
-        let mut lines = reader.lines();
-
-        let directions: Vec<Direction> = lines
-            .next()
-            .unwrap()
-            .unwrap()
-            .chars()
-            .map(|c| match c {
-                'L' => Direction::Left,
-                'R' => Direction::Right,
-                _ => panic!("Invalid direction"),
-            })
-            .collect();
+    pub fn parse(contents: &str) -> Result<Input> {
+        let mut parser = Parser::new(contents);
+
+        let mut section = parser.expect_section("directions")?;
+        let mut directions = Vec::new();
+        while section.peek().is_some_and(|c| c != '\r' && c != '\n') {
+            let start = section.clone();
+            directions.push(match section.consume() {
+                Some('L') => Direction::Left,
+                Some('R') => Direction::Right,
+                c => return Err(start.error(format!("Invalid direction {:?}", c.unwrap()))),
+            });
+        }
+        section.end()?;
+
         let mut elements = Vec::<String>::new();
         let mut left = HashMap::<String, String>::new();
         let mut right = HashMap::<String, String>::new();
 
-        lines.skip(1).map(|line| line.unwrap()).for_each(|line| {
-            elements.push(String::from(&line[0..3]));
-            left.insert(String::from(&line[0..3]), String::from(&line[7..10]));
-            right.insert(String::from(&line[0..3]), String::from(&line[12..15]));
-        });
+        for (element, to_left, to_right) in parser.expect_section("nodes")?.lines(parse_node)? {
+            elements.push(String::from(element));
+            left.insert(String::from(element), String::from(to_left));
+            right.insert(String::from(element), String::from(to_right));
+        }
+        parser.end()?;
 
-        Input {
+        Ok(Input {
             directions,
             elements,
             left,
             right,
-        }
+        })
+    }
+}
+
+/// Splits `AAA = (BBB, CCC)` into its three labels.
+fn parse_node<'a>(parser: &mut Parser<'a>) -> Result<(&'a str, &'a str, &'a str)> {
+    let element = label(parser)?;
+    parser.literal(" = (")?;
+    let to_left = label(parser)?;
+    parser.literal(", ")?;
+    let to_right = label(parser)?;
+    parser.literal(")")?;
+
+    Ok((element, to_left, to_right))
+}
+
+fn label<'a>(parser: &mut Parser<'a>) -> Result<&'a str> {
+    match parser.take_while(|c| c.is_ascii_alphanumeric()) {
+        "" => Err(parser.error("Expected a label")),
+        label => Ok(label),
     }
 }
@@ -1,24 +1,14 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use anyhow::Result;
+use common::Parser;
 
 pub struct Input {
     pub readings: Vec<Vec<i32>>,
 }
 
-pub fn parse(filename: &str) -> Input {
-    let file = File::open(filename).expect("Failed to open input file");
+pub fn parse(contents: &str) -> Result<Input> {
+    let mut parser = Parser::new(contents);
+    let readings = parser.lines(|line| line.numbers())?;
+    parser.end()?;
 
-    Input {
-        readings: BufReader::new(file)
-            .lines()
-            .map(|line| {
-                line.unwrap()
-                    .split_whitespace()
-                    .map(|s| s.parse::<i32>().unwrap())
-                    .collect()
-            })
-            .collect(),
-    }
+    Ok(Input { readings })
 }
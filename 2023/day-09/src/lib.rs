@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::Solution;
+
+use crate::input::Input;
+
+mod input;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        input::parse(input)
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(input
+            .readings
+            .iter()
+            .map(|reading| compute_next(reading))
+            .sum::<i32>())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Ok(input
+            .readings
+            .iter()
+            .map(|reading| compute_previous(reading))
+            .sum::<i32>())
+    }
+}
+
+fn compute_next(reading: &[i32]) -> i32 {
+    let last_reading = *reading.last().unwrap();
+
+    let diff = compute_diff(reading);
+    if diff.iter().all(|d| *d == 0i32) {
+        return last_reading;
+    }
+
+    last_reading + compute_next(&diff)
+}
+
+fn compute_previous(reading: &[i32]) -> i32 {
+    let first_reading = *reading.first().unwrap();
+
+    let diff = compute_diff(reading);
+    if diff.iter().all(|d| *d == 0i32) {
+        return first_reading;
+    }
+
+    first_reading - compute_previous(&diff)
+}
+
+fn compute_diff(reading: &[i32]) -> Vec<i32> {
+    let mut iter = reading.iter();
+    let mut previous = iter.next().unwrap();
+
+    iter.map(|next| {
+        let diff = next - previous;
+        previous = next;
+        diff
+    })
+    .collect()
+}
@@ -0,0 +1,50 @@
+use anyhow::Result;
+use common::{Parser, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+pub type Input = Vec<(u32, u32)>;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        Parser::new(input).lines(|line| {
+            let left = line.number()?;
+            line.spaces();
+
+            Ok((left, line.number()?))
+        })
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        let mut left = input.iter().map(|(left, _)| left).collect::<Vec<_>>();
+        let mut right = input.iter().map(|(_, right)| right).collect::<Vec<_>>();
+
+        left.sort();
+        right.sort();
+
+        Ok(left
+            .iter()
+            .zip(right)
+            .map(|(left, right)| left.abs_diff(*right))
+            .sum::<u32>())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        let mut right_count = HashMap::new();
+
+        input.iter().map(|(_, right)| right).for_each(|right| {
+            let count = right_count.get(right).unwrap_or(&0) + 1;
+            right_count.insert(*right, count);
+        });
+
+        Ok(input
+            .iter()
+            .map(|(left, _)| left)
+            .map(|left| left * right_count.get(left).unwrap_or(&0))
+            .sum::<u32>())
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::Solution;
+use regex::Regex;
+
+pub type Input = String;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)")?
+            .captures_iter(input)
+            .map(|c| {
+                let (_, [a, b]) = c.extract();
+                (a.parse::<u32>().unwrap(), b.parse::<u32>().unwrap())
+            })
+            .map(|(a, b)| a * b)
+            .sum::<u32>())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        let mut enabled = true;
+
+        Ok(Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)")?
+            .captures_iter(input)
+            .map(|c| {
+                let full = c.get(0).unwrap().as_str();
+
+                if full.starts_with("mul") {
+                    let a = c.get(1).unwrap().as_str().parse::<u32>().unwrap();
+                    let b = c.get(2).unwrap().as_str().parse::<u32>().unwrap();
+
+                    Expr::Mul(a, b)
+                } else if full.starts_with("don't") {
+                    Expr::Dont
+                } else if full.starts_with("do") {
+                    Expr::Do
+                } else {
+                    panic!("unexpected");
+                }
+            })
+            .filter_map(|op| match op {
+                Expr::Mul(a, b) => {
+                    if enabled {
+                        Some((a, b))
+                    } else {
+                        None
+                    }
+                }
+                Expr::Do => {
+                    enabled = true;
+                    None
+                }
+                Expr::Dont => {
+                    enabled = false;
+                    None
+                }
+            })
+            .map(|(a, b)| a * b)
+            .sum::<u32>())
+    }
+}
+
+#[derive(Debug)]
+enum Expr {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
@@ -0,0 +1,51 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::Solution;
+use grid::{Dir, Grid, Pos};
+
+pub type Input = Grid<char>;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        input.parse()
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(input
+            .positions()
+            .flat_map(|pos| Dir::ALL.map(|dir| (pos, dir)))
+            .filter(|&(pos, dir)| xmas(input, pos, dir))
+            .count())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Ok(input.positions().filter(|&pos| x_mas(input, pos)).count())
+    }
+}
+
+fn xmas(board: &Input, pos: Pos, dir: Dir) -> bool {
+    board
+        .ray(pos, dir)
+        .take(4)
+        .map(|(_, &c)| c)
+        .eq("XMAS".chars())
+}
+
+fn x_mas(board: &Input, pos: Pos) -> bool {
+    let corner = |dir| board.step(pos, dir).map(|corner| board[corner]);
+    let is_mas = |a, b| {
+        matches!(
+            (corner(a), corner(b)),
+            (Some('M'), Some('S')) | (Some('S'), Some('M'))
+        )
+    };
+
+    board[pos] == 'A'
+        && is_mas(Dir::NorthWest, Dir::SouthEast)
+        && is_mas(Dir::NorthEast, Dir::SouthWest)
+}
@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use common::{Parser, Solution};
+
+mod rules;
+
+pub use rules::{Conflict, OrderError, Ordering, Page, Rules};
+
+#[derive(Debug)]
+pub struct Input {
+    rules: Rules,
+    updates: Vec<Vec<Page>>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        let mut parser = Parser::new(input);
+
+        let rules = parser.expect_section("rules")?.lines(|line| {
+            let before = line.number::<Page>()?;
+            line.literal("|")?;
+            Ok((before, line.number::<Page>()?))
+        })?;
+
+        let updates = parser
+            .expect_section("updates")?
+            .lines(|line| line.separated(",", |p| p.number()))?;
+        parser.end()?;
+
+        Ok(Input {
+            rules: Rules::new(rules),
+            updates,
+        })
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(input
+            .updates
+            .iter()
+            .filter(|update| input.rules.check(update).is_none())
+            .map(|update| update[update.len() / 2])
+            .sum::<Page>())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Ok(reorder(&input.rules, &input.updates)?
+            .iter()
+            .map(|update| update[update.len() / 2])
+            .sum::<Page>())
+    }
+}
+
+/// Every update that's out of order, sorted. Fails rather than guess when the
+/// rules don't settle the order.
+fn reorder(rules: &Rules, updates: &[Vec<Page>]) -> Result<Vec<Vec<Page>>> {
+    updates
+        .iter()
+        .filter(|update| rules.check(update).is_some())
+        .map(|update| {
+            let ordering = rules
+                .sort(update)
+                .with_context(|| format!("Can't reorder {:?}", update))?;
+            if !ordering.unique {
+                bail!("The rules allow more than one order for {:?}", update);
+            }
+            Ok(ordering.pages)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_rules_that_cannot_be_followed() -> Result<()> {
+        let input = Day05::parse("1|2\n2|3\n3|1\n\n3,2,1\n1,2\n")?;
+        assert_eq!(Day05::part_one(&input)?.to_string(), "2");
+        let error = Day05::part_two(&input).err().unwrap();
+        assert!(format!("{:#}", error).contains("cycle"), "{:#}", error);
+
+        assert!(Day05::part_two(&Day05::parse("1|2\n1|3\n\n3,1,2\n")?).is_err());
+        assert!(Day05::part_two(&Day05::parse("1|2\n2|3\n\n1,2,3\n")?).is_ok());
+        Ok(())
+    }
+}
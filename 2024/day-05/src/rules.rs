@@ -0,0 +1,273 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+
+use anyhow::Result;
+use common::Parser;
+
+pub type Page = u32;
+
+/// The precedence graph: every page, and the pages that must come after it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Rules {
+    after: HashMap<Page, HashSet<Page>>,
+}
+
+/// Two pages of an update in the wrong order: `before` should have come first,
+/// but `after` did.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Conflict {
+    pub before: Page,
+    pub after: Page,
+}
+
+/// An update put in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ordering {
+    pub pages: Vec<Page>,
+    /// Whether the rules leave no other order, so the middle page is certain.
+    pub unique: bool,
+}
+
+/// Why `Rules::sort` couldn't put an update in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OrderError {
+    /// These pages each have to come before the next, and the last before the first.
+    Cycle(Vec<Page>),
+    /// The page is in the update more than once.
+    Repeated(Page),
+}
+
+impl Display for OrderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OrderError::Cycle(pages) => {
+                let pages: Vec<_> = pages.iter().map(Page::to_string).collect();
+                write!(f, "Rules go round in a cycle: {}", pages.join(" -> "))
+            }
+            OrderError::Repeated(page) => write!(f, "Page {} is in the update twice", page),
+        }
+    }
+}
+
+impl Error for OrderError {}
+
+impl Rules {
+    /// Builds the graph from `before|after` pairs.
+    pub fn new(pairs: impl IntoIterator<Item = (Page, Page)>) -> Self {
+        let mut after: HashMap<Page, HashSet<Page>> = HashMap::new();
+
+        for (before, later) in pairs {
+            after.entry(before).or_default().insert(later);
+        }
+
+        Self { after }
+    }
+
+    /// Whether a rule says `before` comes before `after`.
+    pub fn precedes(&self, before: Page, after: Page) -> bool {
+        self.after
+            .get(&before)
+            .is_some_and(|pages| pages.contains(&after))
+    }
+
+    /// The first page that breaks a rule, paired with the earliest page it
+    /// should have come before.
+    pub fn check(&self, update: &[Page]) -> Option<Conflict> {
+        let mut earlier = HashMap::new();
+
+        for (position, &page) in update.iter().enumerate() {
+            if let Some(later) = self.after.get(&page) {
+                let first = later
+                    .iter()
+                    .filter_map(|later| Some((earlier.get(later)?, *later)))
+                    .min();
+
+                if let Some((_, after)) = first {
+                    return Some(Conflict {
+                        before: page,
+                        after,
+                    });
+                }
+            }
+            earlier.entry(page).or_insert(position);
+        }
+
+        None
+    }
+
+    /// Topologically sorts the update, using only the rules between its own
+    /// pages. Ties go to whichever page the update had first.
+    pub fn sort(&self, update: &[Page]) -> Result<Ordering, OrderError> {
+        let mut index = HashMap::new();
+        for (position, &page) in update.iter().enumerate() {
+            if index.insert(page, position).is_some() {
+                return Err(OrderError::Repeated(page));
+            }
+        }
+
+        let successors: Vec<Vec<usize>> = update
+            .iter()
+            .map(|page| {
+                self.after.get(page).map_or(Vec::new(), |later| {
+                    later
+                        .iter()
+                        .filter_map(|page| index.get(page).copied())
+                        .collect()
+                })
+            })
+            .collect();
+
+        let mut waiting_on = vec![0; update.len()];
+        for &next in successors.iter().flatten() {
+            waiting_on[next] += 1;
+        }
+
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..update.len())
+            .filter(|&i| waiting_on[i] == 0)
+            .map(Reverse)
+            .collect();
+        let mut pages = Vec::with_capacity(update.len());
+        let mut unique = true;
+
+        while let Some(Reverse(current)) = ready.pop() {
+            unique &= ready.is_empty();
+            pages.push(update[current]);
+
+            for &next in &successors[current] {
+                waiting_on[next] -= 1;
+                if waiting_on[next] == 0 {
+                    ready.push(Reverse(next));
+                }
+            }
+        }
+
+        if pages.len() < update.len() {
+            return Err(OrderError::Cycle(find_cycle(
+                update,
+                &successors,
+                &waiting_on,
+            )));
+        }
+
+        Ok(Ordering { pages, unique })
+    }
+
+    /// Checks updates one line at a time as they're read, so the whole list
+    /// never has to fit in memory.
+    pub fn validate<'a>(
+        &'a self,
+        reader: impl BufRead + 'a,
+    ) -> impl Iterator<Item = Result<(Vec<Page>, Option<Conflict>)>> + 'a {
+        reader
+            .lines()
+            .filter(|line| !line.as_ref().is_ok_and(|line| line.trim().is_empty()))
+            .map(|line| {
+                let line = line?;
+                let mut parser = Parser::new(&line);
+                let update = parser.separated(",", |p| p.number())?;
+                parser.end()?;
+
+                let conflict = self.check(&update);
+                Ok((update, conflict))
+            })
+    }
+}
+
+/// A cycle among the pages the sort never reached. Each of them is still
+/// waiting on another of them, so walking back through those must loop.
+fn find_cycle(update: &[Page], successors: &[Vec<usize>], waiting_on: &[usize]) -> Vec<Page> {
+    let mut predecessor = vec![None; update.len()];
+    for (from, nexts) in successors.iter().enumerate() {
+        if waiting_on[from] > 0 {
+            for &next in nexts {
+                predecessor[next] = Some(from);
+            }
+        }
+    }
+
+    let mut seen = vec![false; update.len()];
+    let mut current = (0..update.len()).find(|&i| waiting_on[i] > 0).unwrap();
+    while !seen[current] {
+        seen[current] = true;
+        current = predecessor[current].unwrap();
+    }
+
+    let mut cycle = vec![update[current]];
+    let mut page = predecessor[current].unwrap();
+    while page != current {
+        cycle.push(update[page]);
+        page = predecessor[page].unwrap();
+    }
+
+    cycle.reverse();
+    cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts_and_says_whether_the_order_is_forced() {
+        let rules = Rules::new([(1, 2), (2, 3), (1, 4)]);
+
+        assert_eq!(
+            rules.sort(&[3, 2, 1]),
+            Ok(Ordering {
+                pages: vec![1, 2, 3],
+                unique: true
+            })
+        );
+        assert_eq!(
+            rules.sort(&[4, 3, 1, 2]),
+            Ok(Ordering {
+                pages: vec![1, 4, 2, 3],
+                unique: false
+            })
+        );
+        assert_eq!(rules.sort(&[1, 2, 1]), Err(OrderError::Repeated(1)));
+        assert_eq!(
+            rules.check(&[2, 4, 1]),
+            Some(Conflict {
+                before: 1,
+                after: 2
+            })
+        );
+    }
+
+    #[test]
+    fn reports_the_pages_in_a_cycle() {
+        let rules = Rules::new([(1, 2), (2, 3), (3, 4), (4, 2), (5, 1)]);
+
+        let Err(OrderError::Cycle(mut cycle)) = rules.sort(&[5, 4, 3, 2, 1]) else {
+            panic!("Expected a cycle");
+        };
+        // the cycle can start at any of its pages
+        let start = cycle.iter().position(|&page| page == 2).unwrap();
+        cycle.rotate_left(start);
+        assert_eq!(cycle, [2, 3, 4]);
+
+        // without page 4 the rules are fine
+        assert!(rules.sort(&[5, 3, 2, 1]).is_ok());
+    }
+
+    #[test]
+    fn validates_a_stream_of_updates() -> Result<()> {
+        let rules = Rules::new([(1, 2), (2, 3)]);
+        let updates = "1,2,3\n\n3,1\n1,x\n";
+
+        let mut results = rules.validate(updates.as_bytes());
+        assert_eq!(results.next().unwrap()?, (vec![1, 2, 3], None));
+        assert_eq!(
+            results.next().unwrap()?,
+            (vec![3, 1], None),
+            "no rule links 1 and 3 directly"
+        );
+        assert!(results.next().unwrap().is_err());
+        assert!(results.next().is_none());
+        Ok(())
+    }
+}
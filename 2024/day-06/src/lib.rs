@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use anyhow::{Context, Result};
+use common::Solution;
+use grid::{Dir, Grid, Pos};
+
+pub type Input = Grid<char>;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        let map: Input = input.parse()?;
+        map.find(|&c| c == '^').context("No guard in map")?;
+
+        Ok(map)
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        let mut visited = Grid::new(input.width(), input.height(), false);
+        let mut guard = Some(get_start(input));
+
+        while let Some(current) = guard {
+            visited[current.pos] = true;
+            guard = current.next(input);
+        }
+
+        Ok(visited.iter().filter(|&(_, &v)| v).count())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        let mut visited = Grid::new(input.width(), input.height(), Visited::default());
+        let guard_start = get_start(input);
+        let mut guard = Some(guard_start);
+
+        while let Some(current) = guard {
+            visited[current.pos][current.dir.index()] = true;
+            guard = current.next(input);
+        }
+
+        let mut unique = HashSet::new();
+        Ok(visited
+            .iter()
+            .flat_map(|(pos, visited)| {
+                Dir::CARDINAL
+                    .into_iter()
+                    .filter(|dir| visited[dir.index()])
+                    .map(move |dir| (pos, dir))
+            })
+            .filter_map(|(pos, dir)| input.step(pos, dir))
+            .filter(|&pos| pos != guard_start.pos)
+            .filter(|&pos| unique.insert(pos))
+            .map(|pos| {
+                let mut input = input.clone();
+                input[pos] = '#';
+                input
+            })
+            .filter(would_loop)
+            .count())
+    }
+}
+
+/// Which directions the guard has left a cell in, indexed by `Dir::index`.
+type Visited = [bool; 4];
+
+#[derive(Debug, Clone, Copy)]
+struct Guard {
+    pos: Pos,
+    dir: Dir,
+}
+
+impl Guard {
+    pub fn next(self, input: &Input) -> Option<Guard> {
+        let ahead = input.step(self.pos, self.dir)?;
+
+        if input[ahead] == '#' {
+            Some(Guard {
+                pos: self.pos,
+                dir: self.dir.turn_right(),
+            })
+        } else {
+            Some(Guard {
+                pos: ahead,
+                dir: self.dir,
+            })
+        }
+    }
+}
+
+fn get_start(input: &Input) -> Guard {
+    Guard {
+        pos: input.find(|&c| c == '^').unwrap(),
+        dir: Dir::North,
+    }
+}
+
+fn would_loop(input: &Input) -> bool {
+    let mut visited = Grid::new(input.width(), input.height(), Visited::default());
+    let mut guard = Some(get_start(input));
+
+    while let Some(current) = guard {
+        let seen = &mut visited[current.pos][current.dir.index()];
+        if *seen {
+            return true;
+        }
+
+        *seen = true;
+        guard = current.next(input);
+    }
+
+    false
+}
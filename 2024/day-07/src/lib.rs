@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::{Parser, Solution};
+use ilog::IntLog;
+
+type Value = u64;
+pub type Input = Vec<Equation>;
+pub struct Equation {
+    result: Value,
+    operands: Vec<Value>,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        Parser::new(input).lines(|line| {
+            let result = line.number()?;
+            line.literal(": ")?;
+            let operands = line.separated(" ", |operand| operand.number())?;
+
+            Ok(Equation { result, operands })
+        })
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(input
+            .iter()
+            .filter(|Equation { result, operands }| {
+                let max = TWO.pow(operands.len() as u32);
+
+                (0..max)
+                    .map(|i| {
+                        let mut result = operands[0];
+                        for (j, operand) in operands.iter().skip(1).enumerate() {
+                            match i & (1 << j) == 0 {
+                                true => result += operand,
+                                false => result *= operand,
+                            }
+                        }
+
+                        result
+                    })
+                    .any(|r| r == *result)
+            })
+            .map(|e| e.result)
+            .sum::<Value>())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Ok(input
+            .iter()
+            .filter(|Equation { result, operands }| {
+                let max = THREE.pow(operands.len() as u32);
+
+                (0..max)
+                    .map(|i| {
+                        let mut i = i;
+                        let mut result = operands[0];
+
+                        for operand in operands.iter().skip(1) {
+                            match i % THREE {
+                                0 => result += operand,
+                                1 => result *= operand,
+                                2 => {
+                                    result =
+                                        result * (TEN.pow(operand.log10() as u32 + 1)) + operand
+                                }
+                                _ => unreachable!(),
+                            }
+
+                            i /= THREE;
+                        }
+
+                        result
+                    })
+                    .any(|r| r == *result)
+            })
+            .map(|e| e.result)
+            .sum::<Value>())
+    }
+}
+
+const TWO: Value = 2;
+const THREE: Value = 3;
+const TEN: Value = 10;
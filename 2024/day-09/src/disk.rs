@@ -0,0 +1,214 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::{self, Display};
+
+use anyhow::{bail, Result};
+
+pub type FileId = u32;
+
+/// Every block on the disk, each either free or part of a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Disk {
+    blocks: Vec<Option<FileId>>,
+}
+
+/// A run of blocks, either one whole file or one gap between files.
+#[derive(Clone, Copy, Debug)]
+struct Span {
+    start: usize,
+    len: usize,
+}
+
+impl Disk {
+    /// Parses a dense disk map, alternating file and free lengths.
+    pub fn parse(map: &str) -> Result<Self> {
+        let mut blocks = Vec::new();
+
+        for (index, c) in map.trim_end().chars().enumerate() {
+            let Some(len) = c.to_digit(10) else {
+                bail!("Unknown length {:?} in disk map", c);
+            };
+            let block = match index % 2 {
+                0 => Some(FileId::try_from(index / 2)?),
+                _ => None,
+            };
+            blocks.extend((0..len).map(|_| block));
+        }
+
+        Ok(Self { blocks })
+    }
+
+    pub fn blocks(&self) -> &[Option<FileId>] {
+        &self.blocks
+    }
+
+    /// The sum of each block's position times its file's ID.
+    pub fn checksum(&self) -> usize {
+        self.blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(index, block)| Some(index * (*block)? as usize))
+            .sum()
+    }
+
+    /// Moves blocks one at a time from the end of the disk into the leftmost
+    /// free block, until there are no gaps left.
+    pub fn compact_blocks(&self) -> Self {
+        let mut blocks = self.blocks.clone();
+        let mut free = 0;
+        let mut end = blocks.len();
+
+        loop {
+            while free < end && blocks[free].is_some() {
+                free += 1;
+            }
+            while end > free && blocks[end - 1].is_none() {
+                end -= 1;
+            }
+            if end - free < 2 {
+                break;
+            }
+
+            blocks.swap(free, end - 1);
+        }
+
+        Self { blocks }
+    }
+
+    /// Moves whole files, highest ID first, into the leftmost gap that fits
+    /// them, if there is one to their left.
+    ///
+    /// Gaps are kept in a heap of start positions per gap size, so finding the
+    /// leftmost that fits is one look at each size rather than a scan.
+    pub fn compact_files(&self) -> Self {
+        let (files, gaps) = self.spans();
+        let mut by_size: Vec<BinaryHeap<Reverse<usize>>> = Vec::new();
+
+        for gap in gaps {
+            if by_size.len() <= gap.len {
+                by_size.resize_with(gap.len + 1, BinaryHeap::new);
+            }
+            by_size[gap.len].push(Reverse(gap.start));
+        }
+
+        let mut blocks = self.blocks.clone();
+
+        for (id, file) in files.iter().enumerate().rev() {
+            let best = (file.len..by_size.len())
+                .filter_map(|size| Some((by_size[size].peek()?.0, size)))
+                .min();
+
+            let Some((start, size)) = best.filter(|&(start, _)| start < file.start) else {
+                continue;
+            };
+
+            by_size[size].pop();
+            if size > file.len {
+                by_size[size - file.len].push(Reverse(start + file.len));
+            }
+
+            blocks[file.start..file.start + file.len].fill(None);
+            blocks[start..start + file.len].fill(Some(id as FileId));
+        }
+
+        Self { blocks }
+    }
+
+    /// Each file's blocks, indexed by ID, and every gap between them.
+    fn spans(&self) -> (Vec<Span>, Vec<Span>) {
+        let mut files: Vec<Span> = Vec::new();
+        let mut gaps = Vec::new();
+        let mut start = 0;
+
+        for run in self.blocks.chunk_by(|a, b| a == b) {
+            let span = Span {
+                start,
+                len: run.len(),
+            };
+
+            match run[0] {
+                None => gaps.push(span),
+                Some(id) => {
+                    let id = id as usize;
+                    if files.len() <= id {
+                        files.resize(id + 1, Span { start: 0, len: 0 });
+                    }
+                    files[id] = span;
+                }
+            }
+            start += run.len();
+        }
+
+        (files, gaps)
+    }
+}
+
+/// The layout as the puzzle draws it, `.` for free blocks and each file's ID
+/// for its own. IDs past 9 go in brackets, as in `[12]`, so they can't be read
+/// as several single-digit blocks.
+impl Display for Disk {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for block in &self.blocks {
+            match block {
+                Some(id @ 0..=9) => write!(f, "{}", id)?,
+                Some(id) => write!(f, "[{}]", id)?,
+                None => write!(f, ".")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_each_strategy() -> Result<()> {
+        let disk = Disk::parse("12345")?;
+        assert_eq!(disk.to_string(), "0..111....22222");
+        assert_eq!(disk.compact_blocks().to_string(), "022111222......");
+        assert_eq!(disk.compact_files(), disk);
+
+        let disk = Disk::parse("2333133121414131402\n")?;
+        assert_eq!(
+            disk.compact_blocks().to_string(),
+            "0099811188827773336446555566.............."
+        );
+        assert_eq!(
+            disk.compact_files().to_string(),
+            "00992111777.44.333....5555.6666.....8888.."
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn brackets_ids_past_nine() -> Result<()> {
+        let disk = Disk::parse(&("10".repeat(10) + "201"))?;
+        assert_eq!(disk.to_string(), "0123456789[10][10][11]");
+
+        let disk = Disk::parse(&("10".repeat(10) + "131"))?;
+        assert_eq!(disk.to_string(), "0123456789[10]...[11]");
+        assert_eq!(disk.compact_files().to_string(), "0123456789[10][11]...");
+        Ok(())
+    }
+
+    #[test]
+    fn handles_more_files_than_fit_in_u16() -> Result<()> {
+        let count = 70_000;
+        // every file one block long with a one block gap after it, so each
+        // file from the back half fills one gap in the front half
+        let disk = Disk::parse(&"11".repeat(count))?;
+
+        let expected: usize = (0..count / 2)
+            .flat_map(|index| [index, count - 1 - index])
+            .enumerate()
+            .map(|(position, id)| position * id)
+            .sum();
+
+        assert_eq!(disk.compact_blocks().checksum(), expected);
+        assert_eq!(disk.compact_files().checksum(), expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::Solution;
+
+mod disk;
+
+pub use disk::{Disk, FileId};
+
+pub type Input = Disk;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        Disk::parse(input)
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(input.compact_blocks().checksum())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Ok(input.compact_files().checksum())
+    }
+}
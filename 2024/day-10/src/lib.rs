@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use anyhow::{Context, Result};
+use common::Solution;
+use grid::{Grid, Pos};
+
+pub type Input = Grid<u8>;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        Grid::parse_with(input, |_, c| {
+            Ok(c.to_digit(10).context("Expected a height")? as u8)
+        })
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        let mut cache: Cache = Grid::new(input.width(), input.height(), HashSet::default());
+
+        input
+            .iter()
+            .filter(|&(_, &height)| height == 9)
+            .for_each(|(pos, _)| {
+                cache[pos].insert(pos);
+            });
+
+        (0..9).rev().for_each(|value| {
+            input
+                .iter()
+                .filter(|&(_, &height)| height == value)
+                .for_each(|(pos, _)| {
+                    let this_cache = input
+                        .neighbors(pos)
+                        .filter(|&next| input[next] == value + 1)
+                        .flat_map(|next| cache[next].iter().copied())
+                        .collect();
+
+                    cache[pos] = this_cache;
+                });
+        });
+
+        Ok(input
+            .iter()
+            .filter(|&(_, &height)| height == 0)
+            .map(|(pos, _)| cache[pos].len() as u32)
+            .sum::<u32>())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        let mut cache = Grid::new(input.width(), input.height(), Option::<u32>::None);
+
+        Ok(input
+            .iter()
+            .filter(|&(_, &height)| height == 0)
+            .map(|(pos, _)| paths_from(input, pos, &mut cache))
+            .sum::<u32>())
+    }
+}
+
+type Cache = Grid<HashSet<Pos>>;
+
+type PathsFromCache = Grid<Option<u32>>;
+fn paths_from(input: &Input, pos: Pos, cache: &mut PathsFromCache) -> u32 {
+    if let Some(result) = cache[pos] {
+        return result;
+    }
+
+    if input[pos] == 9 {
+        cache[pos] = Some(1);
+        return 1;
+    }
+
+    let next = input[pos] + 1;
+    let result = input
+        .neighbors(pos)
+        .filter(|&neighbor| input[neighbor] == next)
+        .map(|neighbor| paths_from(input, neighbor, cache))
+        .sum();
+
+    cache[pos] = Some(result);
+
+    result
+}
@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::Solution;
+
+mod rules;
+
+pub use rules::{Action, Condition, Count, Rule, Rules, Stones, Value};
+
+pub type Input = Vec<Value>;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        input
+            .split_whitespace()
+            .map(|stone| Ok(stone.parse()?))
+            .collect()
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Rules::default().count_after(input, 25)
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Rules::default().count_after(input, 75)
+    }
+}
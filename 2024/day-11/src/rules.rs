@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+use common::Parser;
+
+pub type Value = u64;
+pub type Count = u128;
+
+/// How many of each stone there are. Their order never matters to a rule, so
+/// stones with the same number can all blink together.
+pub type Stones = BTreeMap<Value, Count>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Condition {
+    Equals(Value),
+    EvenDigits,
+    Always,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Replace(Value),
+    /// Into the left and right halves of its digits.
+    Split,
+    Multiply(Value),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub when: Condition,
+    pub then: Action,
+}
+
+/// What every stone does when it blinks, going by the first rule that matches.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules(pub Vec<Rule>);
+
+impl Default for Rules {
+    /// The puzzle's three rules.
+    fn default() -> Self {
+        Self(vec![
+            Rule {
+                when: Condition::Equals(0),
+                then: Action::Replace(1),
+            },
+            Rule {
+                when: Condition::EvenDigits,
+                then: Action::Split,
+            },
+            Rule {
+                when: Condition::Always,
+                then: Action::Multiply(2024),
+            },
+        ])
+    }
+}
+
+impl Rules {
+    /// Parses one rule per line, like `0 -> 1`, `even -> split` or `any -> *2024`.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut parser = Parser::new(text);
+        let mut rules = Vec::new();
+
+        // sections skip any blank lines between rules
+        while let Some(mut section) = parser.section() {
+            rules.extend(section.lines(|line| {
+                line.spaces();
+                let when = if line.eat("even") {
+                    Condition::EvenDigits
+                } else if line.eat("any") {
+                    Condition::Always
+                } else {
+                    Condition::Equals(line.number()?)
+                };
+
+                line.spaces();
+                line.literal("->")?;
+                line.spaces();
+
+                let then = if line.eat("split") {
+                    Action::Split
+                } else if line.eat("*") {
+                    line.spaces();
+                    Action::Multiply(line.number()?)
+                } else {
+                    Action::Replace(line.number()?)
+                };
+                line.spaces();
+
+                Ok(Rule { when, then })
+            })?);
+        }
+
+        Ok(Self(rules))
+    }
+
+    /// The one or two stones a stone turns into.
+    fn apply(&self, stone: Value) -> Result<(Value, Option<Value>)> {
+        let Some(rule) = self.0.iter().find(|rule| match rule.when {
+            Condition::Equals(value) => stone == value,
+            Condition::EvenDigits => digits(stone).is_multiple_of(2),
+            Condition::Always => true,
+        }) else {
+            bail!("No rule for stone {}", stone);
+        };
+
+        Ok(match rule.then {
+            Action::Replace(value) => (value, None),
+            Action::Split => {
+                let splitter = (10 as Value).pow(digits(stone) / 2);
+                (stone / splitter, Some(stone % splitter))
+            }
+            Action::Multiply(factor) => (
+                stone
+                    .checked_mul(factor)
+                    .with_context(|| format!("Stone {} times {} is too big", stone, factor))?,
+                None,
+            ),
+        })
+    }
+
+    /// Every stone after one blink.
+    pub fn blink(&self, stones: &Stones) -> Result<Stones> {
+        let mut next = Stones::new();
+
+        for (&stone, &count) in stones {
+            let (first, second) = self.apply(stone)?;
+            for stone in [Some(first), second].into_iter().flatten() {
+                let total = next.entry(stone).or_default();
+                *total = total
+                    .checked_add(count)
+                    .context("Too many stones to count")?;
+            }
+        }
+
+        Ok(next)
+    }
+
+    /// Every stone after `blinks` blinks, starting from a row of stones.
+    pub fn stones_after(&self, row: &[Value], blinks: usize) -> Result<Stones> {
+        let mut stones = Stones::new();
+        for &stone in row {
+            *stones.entry(stone).or_default() += 1;
+        }
+
+        for _ in 0..blinks {
+            stones = self.blink(&stones)?;
+        }
+
+        Ok(stones)
+    }
+
+    /// How many stones there are after `blinks` blinks.
+    pub fn count_after(&self, row: &[Value], blinks: usize) -> Result<Count> {
+        self.stones_after(row, blinks)?
+            .values()
+            .try_fold(0, |total: Count, &count| total.checked_add(count))
+            .context("Too many stones to count")
+    }
+}
+
+fn digits(value: Value) -> u32 {
+    value.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_every_stone() -> Result<()> {
+        let rules = Rules::default();
+        assert_eq!(
+            rules.stones_after(&[125, 17], 6)?,
+            Stones::from([
+                (0, 2),
+                (2, 4),
+                (3, 1),
+                (4, 1),
+                (6, 2),
+                (7, 1),
+                (8, 1),
+                (40, 2),
+                (48, 2),
+                (80, 1),
+                (96, 1),
+                (2024, 1),
+                (4048, 1),
+                (14168, 1),
+                (2097446912, 1),
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn counts_far_past_u64() -> Result<()> {
+        let rules = Rules::default();
+        assert!(rules.count_after(&[125, 17], 150)? > Count::from(u64::MAX));
+        assert!(rules.count_after(&[125, 17], 300).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn follows_other_rules() -> Result<()> {
+        let rules = Rules::parse("even -> split\nany -> *11\n")?;
+        assert_eq!(
+            rules.0[1],
+            Rule {
+                when: Condition::Always,
+                then: Action::Multiply(11),
+            }
+        );
+
+        // 1 -> 11 -> 1 1 -> 11 11 -> ...
+        assert_eq!(rules.count_after(&[1], 9)?, 16);
+        assert!(Rules::parse("even -> split")?
+            .blink(&Stones::from([(1, 1)]))
+            .is_err());
+
+        let error = Rules::parse("0 -> 1\n\neven => split\n").unwrap_err();
+        assert_eq!(error.to_string(), "Expected \"->\" at line 3, column 6");
+        Ok(())
+    }
+}
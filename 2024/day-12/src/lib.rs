@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::Solution;
+use grid::{Dir, Grid, Pos};
+
+pub type Input = Grid<char>;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        input.parse()
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        let (region_map, region_count) = build_region_map(input);
+
+        let mut perimeter = vec![0; region_count as usize];
+        let mut area = vec![0; region_count as usize];
+
+        for (pos, &region_id) in region_map.iter() {
+            let region_id = region_id as usize;
+            area[region_id] += 1;
+
+            // every side that isn't shared with a cell of the same region is fence
+            perimeter[region_id] += 4 - region_map
+                .neighbors(pos)
+                .filter(|&n| region_map[n] as usize == region_id)
+                .count() as u8;
+        }
+
+        Ok(area
+            .iter()
+            .zip(perimeter.iter())
+            .map(|(&a, &p)| a as usize * p as usize)
+            .sum::<usize>())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        let (region_map, region_count) = build_region_map(input);
+
+        let mut sides = vec![0; region_count as usize];
+        let mut area = vec![0; region_count as usize];
+
+        for (pos, &region_id) in region_map.iter() {
+            area[region_id as usize] += 1;
+
+            // number of sides is equal to the number of corners
+            // a corner is a cell where perpendicular neighbor's equality to the
+            // current region XOR to false
+            let same = |dir| {
+                region_map
+                    .step(pos, dir)
+                    .is_some_and(|n| region_map[n] == region_id)
+            };
+
+            // XX  0X  X0  00  XX  0X  X0  00
+            // XX  XX  XX  XX  0X  0X  0X  0X
+            //  0   1   0   0   0   0   1   1
+
+            for first in Dir::CARDINAL {
+                let second = first.turn_right();
+                let diagonal = diagonal_between(first, second);
+
+                if (!same(first) && !same(second))
+                    || (same(first) && same(second) && !same(diagonal))
+                {
+                    sides[region_id as usize] += 1;
+                }
+            }
+        }
+
+        Ok(area
+            .iter()
+            .zip(sides.iter())
+            .map(|(&a, &p)| a as usize * p as usize)
+            .sum::<usize>())
+    }
+}
+
+type RegionMap = Grid<u16>;
+
+fn diagonal_between(first: Dir, second: Dir) -> Dir {
+    match (first, second) {
+        (Dir::North, Dir::East) => Dir::NorthEast,
+        (Dir::East, Dir::South) => Dir::SouthEast,
+        (Dir::South, Dir::West) => Dir::SouthWest,
+        (Dir::West, Dir::North) => Dir::NorthWest,
+        _ => unreachable!("{:?} and {:?} are not clockwise neighbors", first, second),
+    }
+}
+
+fn build_region_map(input: &Input) -> (RegionMap, u16) {
+    let mut region_map = Grid::new(input.width(), input.height(), None);
+    let mut region_id = 0;
+
+    for pos in input.positions() {
+        if region_map[pos].is_none() {
+            build_region_map_from(&mut region_map, input, pos, region_id);
+            region_id += 1;
+        }
+    }
+
+    (region_map.map(|id| id.unwrap()), region_id)
+}
+
+fn build_region_map_from(
+    region_map: &mut Grid<Option<u16>>,
+    input: &Input,
+    start: Pos,
+    region_id: u16,
+) {
+    let mut stack = vec![start];
+
+    while let Some(pos) = stack.pop() {
+        if region_map[pos].is_some() {
+            continue;
+        }
+
+        region_map[pos] = Some(region_id);
+
+        for neighbor in input.neighbors(pos) {
+            if input[neighbor] == input[pos] {
+                stack.push(neighbor);
+            }
+        }
+    }
+}
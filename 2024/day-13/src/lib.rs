@@ -0,0 +1,174 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::{Params, Parser, Solution};
+
+pub mod linear;
+
+use linear::{min_cost, solve_2x2, Solutions};
+
+// part two's offset, a parameter so other offsets can be tried
+const CONVERSION_ERROR: Value = 10000000000000;
+
+pub struct Input {
+    pub machines: Vec<Machine>,
+    /// How far off every prize turns out to be in part two.
+    pub conversion_error: Value,
+}
+
+type Value = u64;
+
+#[derive(Debug)]
+pub struct Machine {
+    pub button_a: Point,
+    pub button_b: Point,
+    pub prize: Point,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Point {
+    pub x: Value,
+    pub y: Value,
+}
+
+const A_COST: i128 = 3;
+const B_COST: i128 = 1;
+
+/// A line like `Button A: X+94, Y+34` or `Prize: X=8400, Y=5400`.
+fn parse_point(parser: &mut Parser, label: &str, op: &str) -> Result<Point> {
+    parser.literal(label)?;
+    parser.literal(": X")?;
+    parser.literal(op)?;
+    let x = parser.number()?;
+    parser.literal(", Y")?;
+    parser.literal(op)?;
+    let y = parser.number()?;
+    parser.newline()?;
+
+    Ok(Point { x, y })
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Input;
+
+    const PARAMS: &'static [&'static str] = &["conversion_error"];
+
+    fn parse(input: &str) -> Result<Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input> {
+        let mut parser = Parser::new(input);
+        let mut machines = Vec::new();
+
+        while let Some(mut section) = parser.section() {
+            let button_a = parse_point(&mut section, "Button A", "+")?;
+            let button_b = parse_point(&mut section, "Button B", "+")?;
+            let prize = parse_point(&mut section, "Prize", "=")?;
+            section.end()?;
+
+            machines.push(Machine {
+                button_a,
+                button_b,
+                prize,
+            });
+        }
+
+        Ok(Input {
+            machines,
+            conversion_error: params.get("conversion_error", CONVERSION_ERROR)?,
+        })
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(total_cost(input, 0))
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Ok(total_cost(input, input.conversion_error))
+    }
+}
+
+/// The fewest tokens to win every prize that can be won, with `offset` added
+/// to each prize's position.
+fn total_cost(input: &Input, offset: Value) -> i128 {
+    input
+        .machines
+        .iter()
+        .filter_map(|machine| machine.cheapest(offset))
+        .map(|(a_count, b_count)| A_COST * a_count + B_COST * b_count)
+        .sum()
+}
+
+impl Machine {
+    /// The cheapest presses of A and B that reach the prize moved by `offset`
+    /// along both axes, if any do.
+    pub fn cheapest(&self, offset: Value) -> Option<(i128, i128)> {
+        let [a_x, a_y, b_x, b_y] = [
+            self.button_a.x,
+            self.button_a.y,
+            self.button_b.x,
+            self.button_b.y,
+        ]
+        .map(i128::from);
+        let prize_x = i128::from(self.prize.x) + i128::from(offset);
+        let prize_y = i128::from(self.prize.y) + i128::from(offset);
+
+        match solve_2x2([[a_x, b_x], [a_y, b_y]], [prize_x, prize_y]) {
+            Solutions::None => None,
+            Solutions::Unique(counts) => {
+                let [a_count, b_count] = [counts[0], counts[1]];
+                (a_count.is_integer()
+                    && b_count.is_integer()
+                    && a_count >= 0.into()
+                    && b_count >= 0.into())
+                .then(|| (a_count.to_integer(), b_count.to_integer()))
+            }
+            // the buttons move along the same line as the prize, so either
+            // equation says it all
+            Solutions::Infinite { .. } if a_x != 0 || b_x != 0 => {
+                min_cost(a_x, b_x, prize_x, (A_COST, B_COST))
+            }
+            Solutions::Infinite { .. } => min_cost(a_y, b_y, prize_y, (A_COST, B_COST)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn machine(a: (Value, Value), b: (Value, Value), prize: (Value, Value)) -> Machine {
+        let point = |(x, y)| Point { x, y };
+        Machine {
+            button_a: point(a),
+            button_b: point(b),
+            prize: point(prize),
+        }
+    }
+
+    #[test]
+    fn picks_the_cheapest_of_many_ways_to_win() {
+        // B is cheaper per step, even though A covers three times the ground
+        let collinear = machine((6, 3), (2, 1), (20, 10));
+        assert_eq!(collinear.cheapest(0), Some((0, 10)));
+
+        // A is cheaper per step now, but 22 isn't a multiple of 10
+        let collinear = machine((10, 5), (2, 1), (22, 11));
+        assert_eq!(collinear.cheapest(0), Some((2, 1)));
+
+        let off_the_line = machine((6, 3), (2, 1), (20, 11));
+        assert_eq!(off_the_line.cheapest(0), None);
+    }
+
+    #[test]
+    fn moves_prizes_past_u64() {
+        let far = i128::from(Value::MAX) + 5;
+        assert_eq!(
+            machine((1, 0), (0, 1), (5, 5)).cheapest(Value::MAX),
+            Some((far, far))
+        );
+    }
+}
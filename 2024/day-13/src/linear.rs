@@ -0,0 +1,221 @@
+use num_rational::Ratio;
+
+pub type Rational = Ratio<i128>;
+
+/// Every solution of a linear system over the rationals.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Solutions {
+    None,
+    Unique(Vec<Rational>),
+    /// `particular` plus any combination of the `kernel` vectors.
+    Infinite {
+        particular: Vec<Rational>,
+        kernel: Vec<Vec<Rational>>,
+    },
+}
+
+/// Solves `matrix * x = rhs` exactly by Gauss-Jordan elimination. The matrix
+/// may have any shape, with one row per equation.
+pub fn solve(matrix: &[Vec<i128>], rhs: &[i128]) -> Solutions {
+    assert_eq!(matrix.len(), rhs.len(), "one right-hand side per row");
+    let columns = matrix.first().map_or(0, Vec::len);
+
+    let mut rows: Vec<Vec<Rational>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, &value)| {
+            assert_eq!(row.len(), columns, "rows must all be the same length");
+            row.iter()
+                .chain([&value])
+                .map(|&x| Rational::from(x))
+                .collect()
+        })
+        .collect();
+
+    let mut pivots = Vec::new();
+
+    for column in 0..columns {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..rows.len()).find(|&row| rows[row][column] != 0.into()) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+
+        let scale = rows[rank][column];
+        rows[rank].iter_mut().for_each(|x| *x /= scale);
+
+        let pivot_row = rows[rank].clone();
+        for (index, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+            if index != rank && factor != 0.into() {
+                for (x, &p) in row.iter_mut().zip(&pivot_row) {
+                    *x -= factor * p;
+                }
+            }
+        }
+
+        pivots.push(column);
+    }
+
+    // a leftover row of zeros equal to something nonzero
+    if rows[pivots.len()..]
+        .iter()
+        .any(|row| row[columns] != 0.into())
+    {
+        return Solutions::None;
+    }
+
+    let mut particular = vec![Rational::from(0); columns];
+    for (row, &column) in pivots.iter().enumerate() {
+        particular[column] = rows[row][columns];
+    }
+
+    if pivots.len() == columns {
+        return Solutions::Unique(particular);
+    }
+
+    let kernel = (0..columns)
+        .filter(|column| !pivots.contains(column))
+        .map(|free| {
+            let mut vector = vec![Rational::from(0); columns];
+            vector[free] = 1.into();
+            for (row, &column) in pivots.iter().enumerate() {
+                vector[column] = -rows[row][free];
+            }
+            vector
+        })
+        .collect();
+
+    Solutions::Infinite { particular, kernel }
+}
+
+/// Solves `[[a, b], [c, d]] * x = [e, f]` by Cramer's rule, falling back to
+/// elimination when the determinant is zero.
+pub fn solve_2x2([[a, b], [c, d]]: [[i128; 2]; 2], [e, f]: [i128; 2]) -> Solutions {
+    let determinant = a * d - b * c;
+
+    if determinant == 0 {
+        return solve(&[vec![a, b], vec![c, d]], &[e, f]);
+    }
+
+    Solutions::Unique(vec![
+        Rational::new(e * d - b * f, determinant),
+        Rational::new(a * f - e * c, determinant),
+    ])
+}
+
+/// `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`, and `g` never negative.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a.abs(), a.signum(), 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+/// The non-negative integers `(x, y)` with `u * x + v * y == w` that minimise
+/// `costs.0 * x + costs.1 * y`. Costs must not be negative.
+///
+/// Every integer solution is `(x0 + t * v / g, y0 - t * u / g)` for some `t`,
+/// the non-negative ones are a range of `t`, and the cost is linear in `t`, so
+/// the cheapest is at one end of that range.
+pub fn min_cost(u: i128, v: i128, w: i128, costs: (i128, i128)) -> Option<(i128, i128)> {
+    if u == 0 && v == 0 {
+        return (w == 0).then_some((0, 0));
+    }
+
+    let (g, x, y) = extended_gcd(u, v);
+    if w % g != 0 {
+        return None;
+    }
+
+    let (x0, y0) = (x * (w / g), y * (w / g));
+    let (dx, dy) = (v / g, -u / g);
+
+    // the range of t where x0 + t * dx >= 0 and y0 + t * dy >= 0
+    let mut low = i128::MIN;
+    let mut high = i128::MAX;
+    for (start, step) in [(x0, dx), (y0, dy)] {
+        match step.signum() {
+            1 => low = low.max(div_ceil(-start, step)),
+            -1 => high = high.min(div_floor(start, -step)),
+            _ if start < 0 => return None,
+            _ => {}
+        }
+    }
+
+    if low > high {
+        return None;
+    }
+
+    let slope = costs.0 * dx + costs.1 * dy;
+    let t = match slope.signum() {
+        1 | 0 if low != i128::MIN => low,
+        -1 | 0 if high != i128::MAX => high,
+        _ => unreachable!("non-negative costs can't fall forever on an unbounded range"),
+    };
+
+    Some((x0 + t * dx, y0 + t * dy))
+}
+
+fn div_floor(a: i128, b: i128) -> i128 {
+    a.div_euclid(b)
+}
+
+fn div_ceil(a: i128, b: i128) -> i128 {
+    -(-a).div_euclid(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ints(values: &[i128]) -> Vec<Rational> {
+        values.iter().map(|&x| Rational::from(x)).collect()
+    }
+
+    #[test]
+    fn solves_square_systems() {
+        assert_eq!(
+            solve_2x2([[94, 22], [34, 67]], [8400, 5400]),
+            Solutions::Unique(ints(&[80, 40]))
+        );
+        assert_eq!(
+            solve(
+                &[vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]],
+                &[8, -11, -3]
+            ),
+            Solutions::Unique(ints(&[2, 3, -1]))
+        );
+        assert_eq!(
+            solve_2x2([[2, 0], [0, 4]], [1, 1]),
+            Solutions::Unique(vec![Rational::new(1, 2), Rational::new(1, 4)])
+        );
+    }
+
+    #[test]
+    fn reports_degenerate_systems() {
+        assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 7]), Solutions::None);
+        assert_eq!(
+            solve_2x2([[1, 2], [2, 4]], [3, 6]),
+            Solutions::Infinite {
+                particular: ints(&[3, 0]),
+                kernel: vec![ints(&[-2, 1])],
+            }
+        );
+    }
+
+    #[test]
+    fn finds_the_cheapest_integer_solution() {
+        // 3x + 5y = 22 has just the one non-negative solution
+        assert_eq!(min_cost(3, 5, 22, (3, 1)), Some((4, 2)));
+        // 3x + 5y = 30 has three, at either end depending on which is cheaper
+        assert_eq!(min_cost(3, 5, 30, (1, 3)), Some((10, 0)));
+        assert_eq!(min_cost(3, 5, 30, (3, 1)), Some((0, 6)));
+        assert_eq!(min_cost(2, 4, 7, (3, 1)), None);
+        assert_eq!(min_cost(3, 5, 4, (3, 1)), None);
+        assert_eq!(min_cost(0, 5, 10, (3, 1)), Some((0, 2)));
+        assert_eq!(min_cost(0, 0, 0, (3, 1)), Some((0, 0)));
+    }
+}
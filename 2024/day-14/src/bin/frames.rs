@@ -0,0 +1,65 @@
+//! Lists the times the robots cluster most, and writes those frames, or any
+//! others asked for, as images.
+//!
+//! Needs the `image` feature: `cargo run -p day-14 --features image --bin frames`.
+
+use std::env;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use common::InputArgs;
+use day_14::{candidates, Day14};
+
+const USAGE: &str = "Usage: frames [--input <path>] [--time <seconds>]... [--candidates <n>] \
+    [--out <dir>] [--format png|ppm] [--scale <pixels>] [--text] [--param <key>=<value>]...";
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+    let mut input = InputArgs::new(2024, 14);
+    let mut times = Vec::new();
+    let mut count = 5;
+    let mut out = None;
+    let mut format = "png".to_string();
+    let mut scale = 4;
+    let mut text = false;
+
+    while let Some(arg) = args.next() {
+        if input.take(&arg, &mut args)? {
+            continue;
+        }
+        let mut value = || args.next().context(USAGE);
+
+        match arg.as_str() {
+            "--time" => times.push(value()?.parse::<u64>()?),
+            "--candidates" => count = value()?.parse()?,
+            "--out" => out = Some(PathBuf::from(value()?)),
+            "--format" => format = value()?,
+            "--scale" => scale = value()?.parse()?,
+            "--text" => text = true,
+            _ => bail!("Unknown argument: {}\n{}", arg, USAGE),
+        }
+    }
+
+    let input = input.parse::<Day14>()?;
+
+    if times.is_empty() {
+        for candidate in candidates(&input, count).into_iter().take(count) {
+            println!("{:>6}  score {:.3}", candidate.time, candidate.score);
+            times.push(candidate.time);
+        }
+    }
+
+    for time in times {
+        if text {
+            println!("After {} seconds:\n{}", time, input.render(time));
+        }
+
+        if let Some(dir) = &out {
+            let path = dir.join(format!("frame-{}.{}", time, format));
+            input.image(time).save(&path, scale)?;
+            println!("Wrote {}", path.display());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use common::{Params, Parser, Solution};
+
+mod swarm;
+
+pub use swarm::{candidates, Candidate};
+
+const WIDTH: u32 = 101;
+const HEIGHT: u32 = 103;
+const TIME: u32 = 100;
+
+pub struct Input {
+    robots: Vec<Robot>,
+    width: u32,
+    height: u32,
+    time: u32,
+}
+
+pub struct Robot {
+    x: u32,
+    y: u32,
+    dx: u32,
+    dy: u32,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Input;
+
+    const PARAMS: &'static [&'static str] = &["width", "height", "time"];
+
+    fn parse(input: &str) -> Result<Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input> {
+        let width = params.get("width", WIDTH)?;
+        let height = params.get("height", HEIGHT)?;
+        let time = params.get("time", TIME)?;
+
+        if width == 0 || height == 0 {
+            bail!("The room is {}x{}, it needs some space", width, height);
+        }
+
+        let robots = Parser::new(input).lines(|line| {
+            let x = line.field("p=")?;
+            line.literal(",")?;
+            let y = line.signed()?;
+            let dx = line.field::<i32>(" v=")?;
+            line.literal(",")?;
+            let dy = line.signed::<i32>()?;
+
+            let dx = dx.rem_euclid(width as i32) as u32;
+            let dy = dy.rem_euclid(height as i32) as u32;
+
+            Ok(Robot { x, y, dx, dy })
+        })?;
+
+        Ok(Input {
+            robots,
+            width,
+            height,
+            time,
+        })
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        let Input {
+            width,
+            height,
+            time,
+            ..
+        } = *input;
+        let x_middle = width / 2;
+        let y_middle = height / 2;
+        let mut quadrants = [0, 0, 0, 0];
+
+        Ok(input
+            .positions(time as u64)
+            .fold(&mut quadrants, |quadrants, (x, y)| {
+                if x == x_middle || y == y_middle {
+                    return quadrants;
+                }
+
+                match (x < x_middle, y < y_middle) {
+                    (true, true) => quadrants[0] += 1,
+                    (true, false) => quadrants[1] += 1,
+                    (false, true) => quadrants[2] += 1,
+                    (false, false) => quadrants[3] += 1,
+                };
+
+                quadrants
+            })
+            .iter()
+            .product::<usize>())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        // sides that aren't coprime may never line up the clustered rows and columns
+        Ok(candidates(input, 3)
+            .first()
+            .with_context(|| {
+                format!(
+                    "No time in a {}x{} room clusters both ways",
+                    input.width, input.height
+                )
+            })?
+            .time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_quadrants_in_any_room() -> Result<()> {
+        // two robots top left and one in each other quadrant, in a room whose
+        // sides aren't coprime
+        let robots = "p=0,0 v=0,0\np=1,1 v=0,0\np=9,0 v=0,0\np=0,3 v=0,0\np=9,3 v=0,0\n";
+        let parse = |params: &str| Day14::parse_with(robots, &params.parse()?);
+
+        let input = parse("width=10\nheight=4")?;
+        assert_eq!(Day14::part_one(&input)?.to_string(), "2");
+        assert!(parse("width=0\nheight=7").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,229 @@
+use grid::{Grid, Pos, Rgb};
+
+use crate::{Input, Robot};
+
+const FLOOR: Rgb = [16, 24, 16];
+const ROBOT: Rgb = [90, 220, 90];
+
+impl Robot {
+    /// Where the robot is after `time` seconds, wrapping at the room's edges.
+    fn position(&self, time: u64, width: u32, height: u32) -> (u32, u32) {
+        (
+            advance(self.x, self.dx, time, width),
+            advance(self.y, self.dy, time, height),
+        )
+    }
+}
+
+/// `start + velocity * time` modulo `size`, without overflowing for any time.
+fn advance(start: u32, velocity: u32, time: u64, size: u32) -> u32 {
+    let size = size as u64;
+    ((start as u64 + velocity as u64 * (time % size)) % size) as u32
+}
+
+impl Input {
+    /// Every robot's `(x, y)` after `time` seconds.
+    pub fn positions(&self, time: u64) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.robots
+            .iter()
+            .map(move |robot| robot.position(time, self.width, self.height))
+    }
+
+    /// The room after `time` seconds, true where at least one robot stands.
+    pub fn occupied(&self, time: u64) -> Grid<bool> {
+        let mut room = Grid::new(self.width as usize, self.height as usize, false);
+
+        for (x, y) in self.positions(time) {
+            room[Pos::new(y as usize, x as usize)] = true;
+        }
+
+        room
+    }
+
+    /// The room as `#` for robots and `.` for floor.
+    pub fn render(&self, time: u64) -> String {
+        self.occupied(time)
+            .map(|&robot| if robot { '#' } else { '.' })
+            .to_string()
+    }
+
+    pub fn image(&self, time: u64) -> Grid<Rgb> {
+        self.occupied(time)
+            .map(|&robot| if robot { ROBOT } else { FLOOR })
+    }
+
+    /// How long until every robot is back where it started.
+    pub fn period(&self) -> u64 {
+        let (width, height) = (self.width as u64, self.height as u64);
+        width / gcd(width, height) * height
+    }
+}
+
+/// A time when the robots bunch up on both axes at once.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Candidate {
+    pub time: u64,
+    /// Each axis's variance relative to its average over a whole period,
+    /// summed. Around 2 for robots spread at random, lower when they cluster.
+    pub score: f64,
+}
+
+/// Candidate times within one period, most clustered first.
+///
+/// The x coordinates repeat every `width` seconds and the y coordinates every
+/// `height`, so the `per_axis` times of lowest variance on each axis are found
+/// separately, then paired up with the Chinese remainder theorem.
+pub fn candidates(input: &Input, per_axis: usize) -> Vec<Candidate> {
+    let columns = lowest_variance(input, per_axis, input.width, |robot| (robot.x, robot.dx));
+    let rows = lowest_variance(input, per_axis, input.height, |robot| (robot.y, robot.dy));
+    let mut candidates = Vec::new();
+
+    for &(x_time, x_score) in &columns {
+        for &(y_time, y_score) in &rows {
+            if let Some((time, _)) = crt(x_time, input.width as u64, y_time, input.height as u64) {
+                candidates.push(Candidate {
+                    time,
+                    score: x_score + y_score,
+                });
+            }
+        }
+    }
+
+    candidates.sort_by(|a, b| a.score.total_cmp(&b.score).then(a.time.cmp(&b.time)));
+    candidates
+}
+
+/// The `count` times in `0..size` where one axis has the lowest variance,
+/// each with that variance relative to the average.
+fn lowest_variance(
+    input: &Input,
+    count: usize,
+    size: u32,
+    axis: impl Fn(&Robot) -> (u32, u32),
+) -> Vec<(u64, f64)> {
+    let variances: Vec<f64> = (0..size as u64)
+        .map(|time| {
+            variance(
+                input
+                    .robots
+                    .iter()
+                    .map(|robot| {
+                        let (start, velocity) = axis(robot);
+                        advance(start, velocity, time, size) as f64
+                    })
+                    .collect(),
+            )
+        })
+        .collect();
+
+    let average = variances.iter().sum::<f64>() / variances.len() as f64;
+    let mut times: Vec<(u64, f64)> = variances
+        .iter()
+        .enumerate()
+        .map(|(time, variance)| (time as u64, variance / average))
+        .collect();
+
+    times.sort_by(|a, b| a.1.total_cmp(&b.1));
+    times.truncate(count);
+    times
+}
+
+fn variance(values: Vec<f64>) -> f64 {
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / values.len() as f64
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+/// The smallest `t` with `t ≡ a (mod m)` and `t ≡ b (mod n)`, and how often it
+/// repeats, or `None` if both can't hold at once.
+fn crt(a: u64, m: u64, b: u64, n: u64) -> Option<(u64, u64)> {
+    let (m, n) = (m as i128, n as i128);
+    let (g, p, _) = extended_gcd(m, n);
+    let difference = b as i128 - a as i128;
+
+    if difference % g != 0 {
+        return None;
+    }
+
+    let lcm = m / g * n;
+    let k = (difference / g * p).rem_euclid(n / g);
+    Some(((a as i128 + m * k).rem_euclid(lcm) as u64, lcm as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combines_remainders() {
+        assert_eq!(crt(2, 3, 3, 5), Some((8, 15)));
+        assert_eq!(crt(1, 4, 3, 6), Some((9, 12)));
+        assert_eq!(crt(0, 4, 1, 6), None);
+    }
+
+    #[test]
+    fn finds_a_hidden_picture() {
+        let (width, height, picture) = (101, 103, 6_532);
+        let mut seed = 0x2024_u64;
+        let mut random = |below: u32| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % below as u64) as u32
+        };
+
+        // a filled square at `picture` among as many robots scattered at random,
+        // with everything wound back to where it starts
+        let robots = (0..400)
+            .map(|index| {
+                let (x, y) = if index < 200 {
+                    (40 + index % 14, 30 + index / 14)
+                } else {
+                    (random(width), random(height))
+                };
+                let (dx, dy) = (random(width), random(height));
+
+                Robot {
+                    x: advance(x, width - dx, picture, width),
+                    y: advance(y, height - dy, picture, height),
+                    dx,
+                    dy,
+                }
+            })
+            .collect();
+
+        let input = Input {
+            robots,
+            width,
+            height,
+            time: 100,
+        };
+
+        let best = candidates(&input, 3)[0];
+        assert_eq!(best.time, picture);
+        assert!(best.score < 1.5);
+        assert_eq!(input.positions(picture).next(), Some((40, 30)));
+        assert_eq!(input.period(), 10_403);
+    }
+}
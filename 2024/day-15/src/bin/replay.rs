@@ -0,0 +1,102 @@
+//! Replays the robot's moves. Prints the warehouse after a given move, every
+//! frame as text, or saves the run as an animated GIF.
+//!
+//! Needs the `image` feature: `cargo run -p day-15 --features image --bin replay`.
+
+use std::env;
+use std::fs::File;
+use std::io::BufWriter;
+
+use anyhow::{bail, Context, Result};
+use common::InputArgs;
+use day_15::{Day15, Simulation};
+
+const USAGE: &str = "Usage: replay [--input <path>] [--wide] [--at <move>] [--text] \
+    [--gif <path>] [--every <moves>] [--scale <pixels>] [--delay <centiseconds>]";
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+    let mut input = InputArgs::new(2024, 15);
+    let mut wide = false;
+    let mut at = None;
+    let mut text = false;
+    let mut gif = None;
+    let mut every = 1;
+    let mut scale = 4;
+    let mut delay = 5;
+
+    while let Some(arg) = args.next() {
+        if input.take(&arg, &mut args)? {
+            continue;
+        }
+        let mut value = || args.next().context(USAGE);
+
+        match arg.as_str() {
+            "--wide" => wide = true,
+            "--at" => at = Some(value()?.parse::<usize>()?),
+            "--text" => text = true,
+            "--gif" => gif = Some(value()?),
+            "--every" => every = value()?.parse::<usize>()?.max(1),
+            "--scale" => scale = value()?.parse()?,
+            "--delay" => delay = value()?.parse()?,
+            _ => bail!("Unknown argument: {}\n{}", arg, USAGE),
+        }
+    }
+
+    let mut simulation = input.parse::<Day15>()?.simulation(wide);
+
+    if let Some(path) = gif {
+        let mut frames = Vec::new();
+        for_each_frame(&mut simulation, every, |simulation| {
+            frames.push(simulation.warehouse().image());
+        });
+
+        let writer = BufWriter::new(File::create(&path)?);
+        grid::write_gif(frames, scale, delay, writer)?;
+        simulation.seek(0);
+    }
+
+    if text {
+        for_each_frame(&mut simulation, every, |simulation| {
+            println!("{}", describe(simulation));
+        });
+        simulation.seek(0);
+    }
+
+    if let Some(time) = at {
+        simulation.seek(time);
+        println!("{}", describe(&simulation));
+    } else if !text {
+        println!("GPS sum: {}", simulation.run().gps_sum());
+    }
+
+    Ok(())
+}
+
+/// Calls `f` at the start, after every `every` moves, and at the end.
+fn for_each_frame(simulation: &mut Simulation, every: usize, mut f: impl FnMut(&Simulation)) {
+    f(simulation);
+
+    while simulation.forward().is_some() {
+        if simulation.time().is_multiple_of(every) || simulation.time() == simulation.len() {
+            f(simulation);
+        }
+    }
+}
+
+fn describe(simulation: &Simulation) -> String {
+    let heading = match simulation.history().last() {
+        None => "Initial state:".to_string(),
+        Some(step) => {
+            let pushed = match step.boxes.len() {
+                _ if !step.moved => "blocked".to_string(),
+                0 => "moved".to_string(),
+                1 => "pushed 1 box".to_string(),
+                count => format!("pushed {} boxes", count),
+            };
+            format!("Move {} ({:?}), {}:", simulation.time(), step.dir, pushed)
+        }
+    };
+
+    format!("{}\n{}", heading, simulation.warehouse())
+}
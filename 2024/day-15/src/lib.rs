@@ -0,0 +1,74 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::{Parser, Solution};
+use grid::{Dir, Pos};
+
+mod warehouse;
+
+pub use warehouse::{Simulation, Step, Warehouse};
+
+pub struct Input {
+    narrow: Warehouse,
+    wide: Warehouse,
+    moves: Vec<Dir>,
+}
+
+impl Input {
+    /// A fresh simulation of the narrow warehouse, or of part two's wide one.
+    pub fn simulation(&self, wide: bool) -> Simulation {
+        let warehouse = if wide { &self.wide } else { &self.narrow };
+
+        Simulation::new(warehouse.clone(), self.moves.clone())
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        let mut parser = Parser::new(input);
+        let map = parser.expect_section("map")?.rest();
+        let moves = parse_moves(&mut parser.expect_section("moves")?)?;
+        parser.end()?;
+
+        Ok(Input {
+            narrow: Warehouse::parse(map)?,
+            wide: Warehouse::parse_wide(map)?,
+            moves,
+        })
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(input.simulation(false).run().gps_sum())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Ok(input.simulation(true).run().gps_sum())
+    }
+}
+
+fn parse_moves(parser: &mut Parser) -> Result<Vec<Dir>> {
+    let mut moves = Vec::new();
+
+    while let Some(c) = parser.peek() {
+        match c {
+            '<' => moves.push(Dir::West),
+            '>' => moves.push(Dir::East),
+            '^' => moves.push(Dir::North),
+            'v' => moves.push(Dir::South),
+            _ if c.is_whitespace() => {}
+            _ => return Err(parser.error(format!("Invalid move {:?}", c))),
+        }
+        parser.consume();
+    }
+
+    Ok(moves)
+}
+
+/// The map is walled in, so the robot and boxes can always take a step.
+fn next_pos(pos: Pos, dir: Dir) -> Pos {
+    pos.step(dir).expect("the map is walled in")
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+use day_15::Day15;
+
+fn main() -> Result<()> {
+    common::main::<Day15>(2024, 15)
+}
@@ -0,0 +1,413 @@
+use std::fmt::{self, Display};
+
+use anyhow::{bail, Context, Result};
+use grid::{Dir, Grid, Pos, Rgb};
+
+use crate::next_pos;
+
+const FLOOR: Rgb = [24, 24, 32];
+const WALL: Rgb = [110, 110, 120];
+const BOX: Rgb = [190, 140, 70];
+const BOX_EDGE: Rgb = [120, 80, 30];
+const ROBOT: Rgb = [230, 60, 60];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Wall,
+    /// Part of the box with this index into `Warehouse::boxes`.
+    Box(usize),
+}
+
+/// A box by its leftmost cell: `O` boxes are one cell wide, `[]` boxes two.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Package {
+    pos: Pos,
+    width: usize,
+}
+
+impl Package {
+    fn cells(self) -> impl Iterator<Item = Pos> {
+        (0..self.width).map(move |offset| Pos::new(self.pos.row, self.pos.col + offset))
+    }
+}
+
+/// The robot, walls and boxes of either warehouse, narrow or wide.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Warehouse {
+    cells: Grid<Cell>,
+    boxes: Vec<Package>,
+    robot: Pos,
+}
+
+/// What one move did.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub dir: Dir,
+    /// Where the robot was before the move.
+    pub robot: Pos,
+    /// Whether anything moved, rather than the push ending at a wall.
+    pub moved: bool,
+    /// The leftmost cell of every pushed box, before the move.
+    pub boxes: Vec<Pos>,
+}
+
+impl Warehouse {
+    /// Parses a map of `#`, `.`, `@` and boxes, either `O` or `[]`.
+    pub fn parse(map: &str) -> Result<Self> {
+        let mut robot = None;
+        let mut boxes = Vec::new();
+        let mut open = None;
+
+        let cells = Grid::parse_with(map, |pos, c| {
+            if open.is_some() && c != ']' {
+                bail!("Box is missing its right half");
+            }
+
+            match c {
+                '.' => Ok(Cell::Empty),
+                '#' => Ok(Cell::Wall),
+                '@' => {
+                    robot = Some(pos);
+                    Ok(Cell::Empty)
+                }
+                'O' => {
+                    boxes.push(Package { pos, width: 1 });
+                    Ok(Cell::Box(boxes.len() - 1))
+                }
+                '[' => {
+                    boxes.push(Package { pos, width: 2 });
+                    open = Some(boxes.len() - 1);
+                    Ok(Cell::Box(boxes.len() - 1))
+                }
+                ']' => Ok(Cell::Box(
+                    open.take().context("Box is missing its left half")?,
+                )),
+                _ => bail!("Unknown object"),
+            }
+        })?;
+
+        if open.is_some() {
+            bail!("Box at the end of the map is missing its right half");
+        }
+
+        // so the robot and boxes can always take a step
+        let last = (
+            cells.height().saturating_sub(1),
+            cells.width().saturating_sub(1),
+        );
+        if let Some(gap) = cells.positions().find(|&pos| {
+            let edge = pos.row == 0 || pos.col == 0 || pos.row == last.0 || pos.col == last.1;
+            edge && cells[pos] != Cell::Wall
+        }) {
+            bail!(
+                "The map isn't walled in, row {} column {} is open",
+                gap.row + 1,
+                gap.col + 1
+            );
+        }
+
+        Ok(Self {
+            cells,
+            boxes,
+            robot: robot.context("No robot in map")?,
+        })
+    }
+
+    /// Parses a narrow map at double width, the way part two describes.
+    pub fn parse_wide(map: &str) -> Result<Self> {
+        let wide_map = map
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .flat_map(widen)
+                    .chain(['\n'])
+                    .collect::<String>()
+            })
+            .collect::<String>();
+
+        Self::parse(&wide_map)
+    }
+
+    pub fn robot(&self) -> Pos {
+        self.robot
+    }
+
+    /// The sum of every box's GPS coordinate, measured from its left edge.
+    pub fn gps_sum(&self) -> usize {
+        self.boxes
+            .iter()
+            .map(|package| 100 * package.pos.row + package.pos.col)
+            .sum()
+    }
+
+    /// Tries to move the robot, pushing every box in the way. Nothing moves
+    /// if any of them would hit a wall.
+    pub fn step(&mut self, dir: Dir) -> Step {
+        let mut step = Step {
+            dir,
+            robot: self.robot,
+            moved: false,
+            boxes: Vec::new(),
+        };
+
+        let mut pushed = Vec::new();
+        let mut frontier = vec![next_pos(self.robot, dir)];
+
+        while let Some(pos) = frontier.pop() {
+            match self.cells[pos] {
+                Cell::Wall => return step,
+                Cell::Empty => {}
+                Cell::Box(index) if pushed.contains(&index) => {}
+                Cell::Box(index) => {
+                    pushed.push(index);
+                    frontier.extend(
+                        self.boxes[index]
+                            .cells()
+                            .map(|cell| next_pos(cell, dir))
+                            .filter(|&next| self.cells[next] != Cell::Box(index)),
+                    );
+                }
+            }
+        }
+
+        step.boxes = pushed.iter().map(|&index| self.boxes[index].pos).collect();
+        self.shift(&pushed, dir);
+        self.robot = next_pos(self.robot, dir);
+        step.moved = true;
+        step
+    }
+
+    /// Reverses `step`, which must be the last one taken.
+    pub fn undo(&mut self, step: &Step) {
+        if !step.moved {
+            return;
+        }
+
+        let pushed: Vec<_> = step
+            .boxes
+            .iter()
+            .map(|&pos| match self.cells[next_pos(pos, step.dir)] {
+                Cell::Box(index) => index,
+                _ => panic!("Undoing a step that isn't the last one"),
+            })
+            .collect();
+
+        self.shift(&pushed, step.dir.opposite());
+        self.robot = step.robot;
+    }
+
+    /// Moves every box in `pushed` one cell, all at once so they can't overwrite each other.
+    fn shift(&mut self, pushed: &[usize], dir: Dir) {
+        for &index in pushed {
+            for cell in self.boxes[index].cells() {
+                self.cells[cell] = Cell::Empty;
+            }
+        }
+
+        for &index in pushed {
+            self.boxes[index].pos = next_pos(self.boxes[index].pos, dir);
+
+            for cell in self.boxes[index].cells() {
+                self.cells[cell] = Cell::Box(index);
+            }
+        }
+    }
+
+    /// One pixel per cell, with a darker edge on the left of each box so
+    /// neighbouring boxes stay apart.
+    pub fn image(&self) -> Grid<Rgb> {
+        let mut image = self.cells.map(|cell| match cell {
+            Cell::Empty => FLOOR,
+            Cell::Wall => WALL,
+            Cell::Box(_) => BOX,
+        });
+
+        for package in &self.boxes {
+            if package.width > 1 {
+                image[package.pos] = BOX_EDGE;
+            }
+        }
+        image[self.robot] = ROBOT;
+
+        image
+    }
+}
+
+/// The map as the puzzle draws it.
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.cells.height() {
+            for col in 0..self.cells.width() {
+                let pos = Pos::new(row, col);
+
+                let c = match self.cells[pos] {
+                    _ if pos == self.robot => '@',
+                    Cell::Empty => '.',
+                    Cell::Wall => '#',
+                    Cell::Box(index) if self.boxes[index].width == 1 => 'O',
+                    Cell::Box(index) if self.boxes[index].pos == pos => '[',
+                    Cell::Box(_) => ']',
+                };
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Doubles a tile of the narrow map the way the puzzle describes.
+fn widen(c: char) -> [char; 2] {
+    match c {
+        '#' => ['#', '#'],
+        'O' => ['[', ']'],
+        '.' => ['.', '.'],
+        '@' => ['@', '.'],
+        _ => [c, c],
+    }
+}
+
+/// A warehouse and its list of moves, with every step taken so far so it can
+/// be stepped back through.
+#[derive(Clone, Debug)]
+pub struct Simulation {
+    warehouse: Warehouse,
+    moves: Vec<Dir>,
+    history: Vec<Step>,
+}
+
+impl Simulation {
+    pub fn new(warehouse: Warehouse, moves: Vec<Dir>) -> Self {
+        Self {
+            warehouse,
+            moves,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn warehouse(&self) -> &Warehouse {
+        &self.warehouse
+    }
+
+    pub fn history(&self) -> &[Step] {
+        &self.history
+    }
+
+    /// How many moves have been made.
+    pub fn time(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    /// Makes the next move, or returns `None` once they've all been made.
+    pub fn forward(&mut self) -> Option<&Step> {
+        let &dir = self.moves.get(self.history.len())?;
+        let step = self.warehouse.step(dir);
+        self.history.push(step);
+        self.history.last()
+    }
+
+    /// Takes back the last move, returning what it had done.
+    pub fn back(&mut self) -> Option<Step> {
+        let step = self.history.pop()?;
+        self.warehouse.undo(&step);
+        Some(step)
+    }
+
+    /// Steps forward or back until `time` moves have been made.
+    pub fn seek(&mut self, time: usize) {
+        while self.time() > time && self.back().is_some() {}
+        while self.time() < time && self.forward().is_some() {}
+    }
+
+    /// Makes every remaining move.
+    pub fn run(&mut self) -> &Warehouse {
+        self.seek(self.len());
+        &self.warehouse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{parse_file, Solution};
+
+    use super::*;
+    use crate::Day15;
+
+    fn simulation(filename: &str, wide: bool) -> Result<Simulation> {
+        Ok(parse_file::<Day15>(filename)?.simulation(wide))
+    }
+
+    #[test]
+    fn pushes_wide_boxes_sideways_and_up() -> Result<()> {
+        let mut simulation = simulation("example_3.txt", true)?;
+        assert_eq!(
+            simulation.warehouse().to_string(),
+            "##############\n\
+             ##......##..##\n\
+             ##..........##\n\
+             ##....[][]@.##\n\
+             ##....[]....##\n\
+             ##..........##\n\
+             ##############\n"
+        );
+
+        let step = simulation.forward().unwrap().clone();
+        assert!(step.moved);
+        assert_eq!(step.boxes, [Pos::new(3, 8), Pos::new(3, 6)]);
+        assert_eq!(
+            simulation.warehouse().to_string().lines().nth(3),
+            Some("##...[][]@..##")
+        );
+
+        simulation.run();
+        assert_eq!(
+            simulation.warehouse().to_string(),
+            "##############\n\
+             ##...[].##..##\n\
+             ##...@.[]...##\n\
+             ##....[]....##\n\
+             ##..........##\n\
+             ##..........##\n\
+             ##############\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_maps_without_walls_round_them() {
+        let error = Warehouse::parse("###\n.@#\n###\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "The map isn't walled in, row 2 column 1 is open"
+        );
+        assert!(Day15::parse(".@.\n\n^").is_err());
+    }
+
+    #[test]
+    fn steps_back_to_the_start() -> Result<()> {
+        for wide in [false, true] {
+            let mut simulation = simulation("example_1.txt", wide)?;
+            let start = simulation.warehouse().clone();
+
+            simulation.run();
+            let end = simulation.warehouse().clone();
+            assert_ne!(start, end);
+
+            simulation.seek(0);
+            assert_eq!(*simulation.warehouse(), start);
+            simulation.seek(simulation.len());
+            assert_eq!(*simulation.warehouse(), end);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+//! Prints the maze with every best-path tile and arrows along one optimal
+//! route, and optionally saves the same overlay as a PPM or PNG.
+//!
+//! Needs the `image` feature: `cargo run -p day-16 --features image --bin render`.
+
+use std::env;
+
+use anyhow::{bail, Context, Result};
+use common::InputArgs;
+use day_16::{BestPaths, Day16};
+
+const USAGE: &str =
+    "Usage: render [--input <path>] [--image <path.png|path.ppm>] [--scale <pixels>] [--param <key>=<value>]...";
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+    let mut input = InputArgs::new(2024, 16);
+    let mut image = None;
+    let mut scale = 4;
+
+    while let Some(arg) = args.next() {
+        if input.take(&arg, &mut args)? {
+            continue;
+        }
+        let mut value = || args.next().context(USAGE);
+
+        match arg.as_str() {
+            "--image" => image = Some(value()?),
+            "--scale" => scale = value()?.parse()?,
+            _ => bail!("Unknown argument: {}\n{}", arg, USAGE),
+        }
+    }
+
+    let input = input.parse::<Day16>()?;
+    let paths = BestPaths::new(&input).context("No path from start to end")?;
+
+    print!("{}", paths.render(&input));
+    println!(
+        "cost {}, {} tiles on a best path",
+        paths.cost,
+        paths.tiles.len()
+    );
+
+    if let Some(path) = image {
+        paths.image(&input).save(path, scale)?;
+    }
+
+    Ok(())
+}
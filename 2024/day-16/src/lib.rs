@@ -0,0 +1,291 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use common::{Params, Solution};
+use grid::{Dir, Grid, Pos};
+
+mod render;
+
+pub use render::BestPaths;
+
+pub struct Input {
+    map: Map,
+    start: Pos,
+    end: Pos,
+    costs: CostModel,
+}
+
+type Map = Grid<Object>;
+enum Object {
+    /// Floor, where stepping on costs the model's move cost times the weight.
+    Empty(Cost),
+    Wall,
+}
+
+#[derive(Hash, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct State {
+    pub position: Pos,
+    pub facing: Dir,
+}
+
+pub type Cost = u64;
+
+/// What the reindeer pays to move and turn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CostModel {
+    /// Per step onto a tile of weight 1. `.` tiles weigh 1, digits weigh themselves.
+    pub step: Cost,
+    /// Per turn of 90 degrees, or of 45 degrees with `diagonal`.
+    pub turn: Cost,
+    /// Per turn of 180 degrees in one go.
+    pub reverse: Cost,
+    /// Whether the reindeer can face, and so move, diagonally.
+    pub diagonal: bool,
+}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        Self {
+            step: 1,
+            turn: 1000,
+            reverse: 2000,
+            diagonal: false,
+        }
+    }
+}
+
+impl CostModel {
+    pub fn from_params(params: &Params) -> Result<Self> {
+        let defaults = Self::default();
+        let turn = params.get("turn", defaults.turn)?;
+        let diagonal = params.get("diagonal", defaults.diagonal)?;
+        // unless given, the same as turning round one turn at a time
+        let turns = if diagonal { 4 } else { 2 };
+
+        Ok(Self {
+            step: params.get("move", defaults.step)?,
+            turn,
+            // too big to hold is too big for `check_fits` anyway
+            reverse: params.get("reverse", turn.saturating_mul(turns))?,
+            diagonal,
+        })
+    }
+
+    /// Fails if some path through `map` could cost more than a `Cost` holds.
+    /// An optimal path never visits a state twice, so no cost the search adds
+    /// up is more than the priciest edge times the number of states.
+    fn check_fits(&self, map: &Map) -> Result<()> {
+        let heaviest = map
+            .iter()
+            .filter_map(|(_, object)| match object {
+                Object::Empty(weight) => Some(*weight),
+                Object::Wall => None,
+            })
+            .max()
+            .unwrap_or(0);
+        let facings = if self.diagonal { 8 } else { 4 };
+        let states = (map.width() * map.height() * facings) as Cost;
+
+        self.step
+            .checked_mul(heaviest)
+            .map(|step| step.max(self.turn).max(self.reverse))
+            .and_then(|edge| edge.checked_mul(states))
+            .map(|_| ())
+            .context("Moves and turns cost too much to add up a path")
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Input;
+
+    const PARAMS: &'static [&'static str] = &["move", "turn", "reverse", "diagonal"];
+
+    fn parse(input: &str) -> Result<Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input> {
+        let mut start = None;
+        let mut end = None;
+
+        let map = Grid::parse_with(input, |pos, c| match c {
+            '.' => Ok(Object::Empty(1)),
+            '#' => Ok(Object::Wall),
+            'S' => {
+                start = Some(pos);
+                Ok(Object::Empty(1))
+            }
+            'E' => {
+                end = Some(pos);
+                Ok(Object::Empty(1))
+            }
+            '0'..='9' => Ok(Object::Empty(c.to_digit(10).unwrap().into())),
+            _ => bail!("Unknown object"),
+        })?;
+
+        let costs = CostModel::from_params(params)?;
+        costs.check_fits(&map)?;
+
+        Ok(Input {
+            map,
+            start: start.context("No start in map")?,
+            end: end.context("No end in map")?,
+            costs,
+        })
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(search::dijkstra(
+            start_state(input),
+            |state| successors(input, state),
+            |state| state.position == input.end,
+        )
+        .context("Failed to find path")?
+        .cost)
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        let optimal = search::all_optimal(
+            start_state(input),
+            |state| successors(input, state),
+            |state| state.position == input.end,
+        )
+        .context("Failed to find path")?;
+
+        Ok(optimal
+            .states()
+            .into_iter()
+            .map(|state| state.position)
+            .collect::<HashSet<_>>()
+            .len())
+    }
+}
+
+fn start_state(input: &Input) -> State {
+    State {
+        position: input.start,
+        facing: Dir::East,
+    }
+}
+
+fn successors(input: &Input, state: &State) -> impl Iterator<Item = (State, Cost)> {
+    let costs = input.costs;
+
+    // stepping off the edge of a maze that isn't walled in is as good as a wall
+    let forward =
+        state
+            .position
+            .step(state.facing)
+            .and_then(|ahead| match input.map.get(ahead)? {
+                Object::Empty(weight) => Some((
+                    State {
+                        position: ahead,
+                        facing: state.facing,
+                    },
+                    costs.step * weight,
+                )),
+                Object::Wall => None,
+            });
+
+    let (left, right) = if costs.diagonal {
+        (state.facing.turn_left_45(), state.facing.turn_right_45())
+    } else {
+        (state.facing.turn_left(), state.facing.turn_right())
+    };
+
+    let turns = [
+        (left, costs.turn),
+        (right, costs.turn),
+        (state.facing.opposite(), costs.reverse),
+    ]
+    .map(|(facing, cost)| {
+        (
+            State {
+                position: state.position,
+                facing,
+            },
+            cost,
+        )
+    });
+
+    forward.into_iter().chain(turns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(maze: &str, params: &str) -> (String, String) {
+        let input = Day16::parse_with(maze, &params.parse().unwrap()).unwrap();
+
+        let part_one = Day16::part_one(&input).unwrap().to_string();
+        let part_two = Day16::part_two(&input).unwrap().to_string();
+        (part_one, part_two)
+    }
+
+    #[test]
+    fn turns_around_in_one_go() {
+        let maze = "#####\n#E.S#\n#####\n";
+
+        assert_eq!(solve(maze, ""), ("2002".into(), "3".into()));
+        assert_eq!(solve(maze, "reverse=500"), ("502".into(), "3".into()));
+    }
+
+    #[test]
+    fn weighs_digit_tiles() {
+        let maze = "#####\n#E..#\n#9#.#\n#S..#\n#####\n";
+
+        assert_eq!(solve(maze, "").0, "1010");
+        // cheap turns make going round the heavy tile worth it
+        assert_eq!(solve(maze, "turn=1\nmove=1"), ("8".into(), "7".into()));
+    }
+
+    #[test]
+    fn moves_diagonally() {
+        let maze = "######\n#...E#\n#....#\n#S...#\n######\n";
+
+        assert_eq!(solve(maze, "").0, "1005");
+        assert_eq!(solve(maze, "diagonal=true"), ("1003".into(), "4".into()));
+        // a U-turn is four 45 degree turns
+        assert_eq!(solve("#E.S#\n", "diagonal=true").0, "4002");
+    }
+
+    #[test]
+    fn stays_inside_unwalled_mazes() {
+        let maze = "...\nS#E\n";
+
+        assert_eq!(solve(maze, ""), ("3004".into(), "5".into()));
+        assert_eq!(solve("ES\n", ""), ("2001".into(), "2".into()));
+    }
+
+    #[test]
+    fn handles_free_turns() {
+        let maze = "#####\n#E.S#\n#####\n";
+
+        assert_eq!(solve(maze, "turn=0"), ("2".into(), "3".into()));
+        let input = Day16::parse_with(maze, &"turn=0".parse().unwrap()).unwrap();
+        assert_eq!(BestPaths::new(&input).unwrap().route.len(), 4);
+    }
+
+    #[test]
+    fn solves_or_rejects_huge_costs() {
+        let maze = "#####\n#E.S#\n#####\n";
+
+        assert_eq!(
+            solve(maze, "turn=4000000000"),
+            ("8000000002".into(), "3".into())
+        );
+        assert_eq!(
+            solve(maze, "move=1000000000"),
+            ("2000002000".into(), "3".into())
+        );
+
+        let parse = |params: &str| Day16::parse_with(maze, &params.parse().unwrap());
+        assert!(parse("move=9223372036854775807").is_err());
+        assert!(parse("turn=9223372036854775807").is_err());
+        assert!(parse("turn=9223372036854775807\nreverse=1").is_err());
+    }
+}
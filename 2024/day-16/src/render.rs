@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+
+use grid::{Dir, Grid, Pos, Rgb};
+
+use crate::{start_state, successors, Cost, Input, Object, State};
+
+const WALL: Rgb = [48, 48, 48];
+const FLOOR: Rgb = [232, 232, 232];
+const BEST: Rgb = [140, 185, 240];
+const ROUTE: Rgb = [250, 165, 50];
+const ARROW: Rgb = [120, 60, 0];
+const START: Rgb = [40, 170, 70];
+const END: Rgb = [215, 50, 50];
+
+/// Every tile on any optimal path, plus one of those paths to draw arrows along.
+pub struct BestPaths {
+    pub cost: Cost,
+    pub tiles: HashSet<Pos>,
+    pub route: Vec<State>,
+}
+
+impl BestPaths {
+    pub fn new(input: &Input) -> Option<Self> {
+        let optimal = search::all_optimal(
+            start_state(input),
+            |state| successors(input, state),
+            |state| state.position == input.end,
+        )?;
+
+        // any way back will do, they're all optimal, but free turns can make
+        // predecessors go round in circles, so search back for the start
+        let start = start_state(input);
+        let mut route = search::bfs(
+            optimal.goals[0],
+            |state| optimal.predecessors(state).to_vec(),
+            |state| *state == start,
+        )?
+        .states;
+        route.reverse();
+
+        Some(Self {
+            cost: optimal.cost,
+            tiles: optimal
+                .states()
+                .into_iter()
+                .map(|state| state.position)
+                .collect(),
+            route,
+        })
+    }
+
+    /// The way the route leaves each tile it crosses, after any turns there.
+    fn facings(&self, input: &Input) -> Grid<Option<Dir>> {
+        let mut facings = Grid::new(input.map.width(), input.map.height(), None);
+
+        for state in &self.route {
+            if state.position != input.end {
+                facings[state.position] = Some(state.facing);
+            }
+        }
+
+        facings
+    }
+
+    /// The map with `S` and `E`, arrows along the route and `O` on every other
+    /// best-path tile.
+    pub fn render(&self, input: &Input) -> String {
+        let facings = self.facings(input);
+        let mut text = String::new();
+
+        for row in 0..input.map.height() {
+            for col in 0..input.map.width() {
+                let pos = Pos::new(row, col);
+
+                text.push(match input.map[pos] {
+                    _ if pos == input.start => 'S',
+                    _ if pos == input.end => 'E',
+                    Object::Wall => '#',
+                    Object::Empty(_) => match facings[pos] {
+                        Some(facing) => arrow(facing),
+                        None if self.tiles.contains(&pos) => 'O',
+                        None => '.',
+                    },
+                });
+            }
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// The same overlay with three pixels per tile each way, so the route's
+    /// tiles can point the way they're left.
+    pub fn image(&self, input: &Input) -> Grid<Rgb> {
+        let facings = self.facings(input);
+        let mut image = Grid::new(input.map.width() * 3, input.map.height() * 3, FLOOR);
+
+        for (pos, object) in input.map.iter() {
+            let facing = facings[pos];
+            let colour = match object {
+                _ if pos == input.start => START,
+                _ if pos == input.end => END,
+                Object::Wall => WALL,
+                Object::Empty(_) if facing.is_some() => ROUTE,
+                Object::Empty(_) if self.tiles.contains(&pos) => BEST,
+                Object::Empty(_) => FLOOR,
+            };
+
+            let centre = Pos::new(pos.row * 3 + 1, pos.col * 3 + 1);
+            for row in centre.row - 1..=centre.row + 1 {
+                for col in centre.col - 1..=centre.col + 1 {
+                    image[Pos::new(row, col)] = colour;
+                }
+            }
+
+            if let Some(facing) = facing {
+                image[centre] = ARROW;
+                image[centre.step(facing).unwrap()] = ARROW;
+            }
+        }
+
+        image
+    }
+}
+
+fn arrow(facing: Dir) -> char {
+    match facing {
+        Dir::North => '^',
+        Dir::East => '>',
+        Dir::South => 'v',
+        Dir::West => '<',
+        Dir::NorthEast | Dir::SouthWest => '/',
+        Dir::SouthEast | Dir::NorthWest => '\\',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use common::parse_file;
+
+    use super::*;
+    use crate::Day16;
+
+    #[test]
+    fn renders_the_best_paths() -> Result<()> {
+        let input = parse_file::<Day16>("example_1.txt")?;
+        let paths = BestPaths::new(&input).unwrap();
+        let text = paths.render(&input);
+
+        assert_eq!(paths.cost, 7036);
+        assert_eq!(text.lines().count(), input.map.height());
+        assert_eq!(text.chars().filter(|c| !"#.\n".contains(*c)).count(), 45);
+        assert_eq!(
+            text.chars().filter(|c| "^>v<".contains(*c)).count(),
+            paths
+                .route
+                .iter()
+                .map(|state| state.position)
+                .collect::<HashSet<_>>()
+                .len()
+                - 2
+        );
+
+        let image = paths.image(&input);
+        assert_eq!(image.width(), input.map.width() * 3);
+        assert_eq!(
+            image[Pos::new(input.start.row * 3, input.start.col * 3)],
+            START
+        );
+        Ok(())
+    }
+}
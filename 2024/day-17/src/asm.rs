@@ -0,0 +1,235 @@
+use std::fmt::{self, Display, Write};
+
+use anyhow::Result;
+use common::Parser;
+
+use crate::{ComboOperand, Instruction, LiteralOperand};
+
+/// Indexed by opcode.
+const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mnemonic = MNEMONICS[self.opcode() as usize];
+
+        match self {
+            Instruction::Adv(operand)
+            | Instruction::Bst(operand)
+            | Instruction::Out(operand)
+            | Instruction::Bdv(operand)
+            | Instruction::Cdv(operand) => write!(f, "{} {}", mnemonic, operand),
+            Instruction::Bxl(operand) | Instruction::Jnz(operand) => {
+                write!(f, "{} {}", mnemonic, operand)
+            }
+            Instruction::Bxc => write!(f, "{}", mnemonic),
+        }
+    }
+}
+
+impl Display for LiteralOperand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Registers print as `a`, `b` and `c`, the reserved operand 7 as `?`.
+impl Display for ComboOperand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            0..=3 => write!(f, "{}", self.0),
+            4 => write!(f, "a"),
+            5 => write!(f, "b"),
+            6 => write!(f, "c"),
+            _ => write!(f, "?"),
+        }
+    }
+}
+
+/// Renders `program` one instruction per line, prefixed by its address:
+///
+/// ```text
+///   0: adv 3
+///   2: out a
+///   4: jnz 0
+/// ```
+///
+/// Pairs that can't run, such as an unknown opcode or the reserved combo
+/// operand, and a trailing odd byte become `.byte` lines, so `assemble` always
+/// gives the same bytes back.
+pub fn disassemble(program: &[u8]) -> String {
+    let mut text = String::new();
+
+    for (index, pair) in program.chunks(2).enumerate() {
+        let line = match *pair {
+            [opcode, operand] => match Instruction::decode(index * 2, opcode, operand) {
+                Ok(Instruction::Bxc) if operand != 0 => format!("bxc {}", operand),
+                Ok(instruction) => instruction.to_string(),
+                Err(_) => format!(".byte {}, {}", opcode, operand),
+            },
+            [byte] => format!(".byte {}", byte),
+            _ => unreachable!("chunks of two"),
+        };
+
+        writeln!(text, "{:>3}: {}", index * 2, line).unwrap();
+    }
+
+    text
+}
+
+/// Parses the text `disassemble` produces back into bytes. Addresses are
+/// optional but checked when given, `bxc` takes an optional ignored operand,
+/// and `#` starts a comment.
+pub fn assemble(text: &str) -> Result<Vec<u8>> {
+    let mut parser = Parser::new(text);
+    let mut program = Vec::new();
+
+    while !parser.is_empty() {
+        parser.spaces();
+
+        if parser.peek().is_some_and(|c| c.is_ascii_digit()) {
+            let start = parser.clone();
+            let address = parser.number::<usize>()?;
+            parser.literal(":")?;
+            parser.spaces();
+
+            if address != program.len() {
+                return Err(start.error(format!(
+                    "Address {} should be {}",
+                    address,
+                    program.len()
+                )));
+            }
+        }
+
+        let start = parser.clone();
+        let word = parser.take_while(|c| c.is_ascii_alphabetic() || c == '.');
+        parser.spaces();
+
+        match word {
+            "" => {}
+            ".byte" => program.extend(parser.separated(",", |p| {
+                p.spaces();
+                p.number::<u8>()
+            })?),
+            _ => {
+                let opcode = MNEMONICS
+                    .iter()
+                    .position(|&mnemonic| mnemonic == word)
+                    .ok_or_else(|| start.error(format!("Unknown instruction {:?}", word)))?
+                    as u8;
+
+                let operand = match opcode {
+                    4 if matches!(parser.peek(), None | Some('\r' | '\n' | '#')) => 0,
+                    1 | 3 | 4 => literal(&mut parser)?,
+                    _ => combo(&mut parser)?,
+                };
+
+                program.extend([opcode, operand]);
+            }
+        }
+
+        parser.spaces();
+        if parser.eat("#") {
+            parser.line();
+        }
+        parser.newline()?;
+    }
+
+    Ok(program)
+}
+
+fn literal(parser: &mut Parser) -> Result<u8> {
+    let start = parser.clone();
+
+    match parser.number()? {
+        operand @ 0..=7 => Ok(operand),
+        operand => Err(start.error(format!("Literal operand {} is not 0-7", operand))),
+    }
+}
+
+fn combo(parser: &mut Parser) -> Result<u8> {
+    for (register, operand) in [("a", 4), ("b", 5), ("c", 6)] {
+        if parser.eat(register) {
+            return Ok(operand);
+        }
+    }
+
+    let start = parser.clone();
+
+    match parser.number()? {
+        operand @ 0..=3 => Ok(operand),
+        operand => Err(start.error(format!("Combo operand {} is not 0-3, a, b or c", operand))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse_file;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::Day17;
+
+    #[test]
+    fn disassembles_the_quine() -> Result<()> {
+        let computer = parse_file::<Day17>("example_2.txt")?;
+
+        assert_eq!(
+            disassemble(computer.program()),
+            "  0: adv 3\n  2: out a\n  4: jnz 0\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn round_trips_programs() -> Result<()> {
+        let mut programs = vec![
+            vec![2, 6],
+            vec![5, 0, 5, 1, 5, 4],
+            vec![1, 7, 4, 0, 4, 3],
+            // not runnable: opcode 9, reserved operand, out-of-range operand, odd length
+            vec![9, 1, 0, 7, 3, 8, 5],
+        ];
+
+        for filename in ["example.txt", "example_2.txt"] {
+            programs.push(parse_file::<Day17>(filename)?.program().to_vec());
+        }
+
+        for program in programs {
+            let text = disassemble(&program);
+            assert_eq!(assemble(&text)?, program, "{}", text);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn assembles_hand_written_programs() -> Result<()> {
+        let text = "
+            # halve A until it's zero, printing it each time
+            adv 1
+            out a
+            jnz 0 # back to the start
+        ";
+        assert_eq!(assemble(text)?, [0, 1, 5, 4, 3, 0]);
+
+        let error = assemble("0: bst a\n2: out 4\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Combo operand 4 is not 0-3, a, b or c at line 2, column 8"
+        );
+
+        let error = assemble("0: bst a\n4: out b\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Address 4 should be 2 at line 2, column 1"
+        );
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips_any_bytes(program in prop::collection::vec(any::<u8>(), 0..32)) {
+            prop_assert_eq!(assemble(&disassemble(&program)).unwrap(), program);
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Steps through a day 17 program. With `--trace` it runs to the end and dumps
+//! every step, otherwise it reads debugger commands from stdin.
+
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use common::InputArgs;
+use day_17::{disassemble, trace_json, trace_text, Day17, Debugger, Register};
+
+const USAGE: &str = "Usage: debug [--input <path>] [--register <r>=<value>]... \
+    [--break <ip>]... [--watch <r>]... [--trace text|json] [--param budget=<steps>]";
+
+const HELP: &str = "\
+s, step [n]       execute n instructions (default 1)
+c, continue       run to the next breakpoint, watched change or halt
+b, break <ip>     stop before the instruction at ip
+d, delete <ip>    remove a breakpoint
+w, watch <r>      stop after register a, b or c changes
+u, unwatch <r>    stop watching a register
+r, registers      show the instruction pointer, registers and output
+l, list           disassemble, marking the ip (>) and breakpoints (*)
+t, trace [json]   dump every step so far
+q, quit";
+
+#[derive(Clone, Copy, Debug)]
+enum TraceFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for TraceFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(format: &str) -> Result<Self> {
+        match format {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => bail!("Unknown trace format {:?}, expected text or json", format),
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+    let mut input = InputArgs::new(2024, 17);
+    let mut registers = Vec::new();
+    let mut breakpoints = Vec::new();
+    let mut watches = Vec::new();
+    let mut trace = None;
+
+    while let Some(arg) = args.next() {
+        if input.take(&arg, &mut args)? {
+            continue;
+        }
+        let mut value = || args.next().context(USAGE);
+
+        match arg.as_str() {
+            "--register" => {
+                let pair = value()?;
+                let (register, value) = pair.split_once('=').context(USAGE)?;
+                registers.push((register.parse::<Register>()?, value.parse::<u64>()?));
+            }
+            "--break" => breakpoints.push(value()?.parse::<usize>()?),
+            "--watch" => watches.push(value()?.parse::<Register>()?),
+            "--trace" => trace = Some(value()?.parse::<TraceFormat>()?),
+            _ => bail!("Unknown argument: {}\n{}", arg, USAGE),
+        }
+    }
+
+    let mut computer = input.parse::<Day17>()?;
+    for (register, value) in registers {
+        computer.set_register(register, value);
+    }
+
+    let mut debugger = Debugger::new(computer);
+    for ip in breakpoints {
+        debugger.set_breakpoint(ip);
+    }
+    for register in watches {
+        debugger.watch(register);
+    }
+
+    match trace {
+        Some(format) => {
+            let result = loop {
+                match debugger.step() {
+                    Ok(Some(_)) => {}
+                    Ok(None) => break Ok(()),
+                    Err(error) => break Err(error),
+                }
+            };
+
+            print_trace(&debugger, format)?;
+
+            Ok(result?)
+        }
+        None => repl(debugger),
+    }
+}
+
+fn repl(mut debugger: Debugger) -> Result<()> {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        print!("({}) ", debugger.computer().instruction_pointer());
+        io::stdout().flush()?;
+
+        let Some(line) = lines.next().transpose()? else {
+            return Ok(());
+        };
+
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            continue;
+        };
+        let argument = words.next();
+
+        if matches!(command, "q" | "quit") {
+            return Ok(());
+        }
+
+        if let Err(error) = run_command(&mut debugger, command, argument) {
+            println!("{}", error);
+        }
+    }
+}
+
+fn run_command(debugger: &mut Debugger, command: &str, argument: Option<&str>) -> Result<()> {
+    let ip = || -> Result<usize> {
+        argument
+            .context("Expected an instruction pointer")?
+            .parse()
+            .context("Invalid instruction pointer")
+    };
+    let register = || -> Result<Register> { argument.context("Expected a register")?.parse() };
+
+    match command {
+        "s" | "step" => {
+            let count = argument.map_or(Ok(1), str::parse::<usize>)?;
+
+            for _ in 0..count {
+                match debugger.step()? {
+                    Some(step) => println!("{}", step),
+                    None => {
+                        println!("halted");
+                        break;
+                    }
+                }
+            }
+        }
+        "c" | "continue" => {
+            let stop = debugger.resume();
+            if let Some(step) = debugger.trace().last() {
+                println!("{}", step);
+            }
+            println!("{}", stop);
+        }
+        "b" | "break" => debugger.set_breakpoint(ip()?),
+        "d" | "delete" => {
+            if !debugger.clear_breakpoint(ip()?) {
+                bail!("No breakpoint there");
+            }
+        }
+        "w" | "watch" => debugger.watch(register()?),
+        "u" | "unwatch" => {
+            if !debugger.unwatch(register()?) {
+                bail!("Not watching that register");
+            }
+        }
+        "r" | "registers" => {
+            let computer = debugger.computer();
+            let registers = computer.registers();
+            let output: Vec<_> = computer.output().iter().map(u8::to_string).collect();
+
+            println!(
+                "ip: {}  a: {}  b: {}  c: {}  output: {}",
+                computer.instruction_pointer(),
+                registers.a,
+                registers.b,
+                registers.c,
+                output.join(",")
+            );
+        }
+        "l" | "list" => {
+            let ip = debugger.computer().instruction_pointer();
+
+            for (index, line) in disassemble(debugger.computer().program())
+                .lines()
+                .enumerate()
+            {
+                let address = index * 2;
+                let current = if address == ip { '>' } else { ' ' };
+                let breakpoint = if debugger.breakpoints().contains(&address) {
+                    '*'
+                } else {
+                    ' '
+                };
+                println!("{}{}{}", current, breakpoint, line);
+            }
+        }
+        "t" | "trace" => {
+            let format = argument.map_or(Ok(TraceFormat::Text), str::parse)?;
+            print_trace(debugger, format)?;
+        }
+        "h" | "help" => println!("{}", HELP),
+        _ => bail!("Unknown command {:?}, try help", command),
+    }
+
+    Ok(())
+}
+
+fn print_trace(debugger: &Debugger, format: TraceFormat) -> Result<()> {
+    match format {
+        TraceFormat::Text => print!("{}", trace_text(debugger.trace())),
+        TraceFormat::Json => println!("{}", trace_json(debugger.trace())?),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,327 @@
+use std::collections::BTreeMap;
+use std::ops::{ControlFlow, Range};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+use crate::{divide, ComboOperand, Computer, ExecError, Instruction};
+
+/// How many values of A a worker runs before handing its outputs back.
+const CHUNK: u64 = 4096;
+
+/// A program decoded once, for running it with many values of A.
+///
+/// Every address gets an `Op`, odd ones included since `jnz` can land there,
+/// with combo operands already resolved to a constant or a register. A run
+/// only touches three registers and the caller's output buffer.
+#[derive(Clone, Debug)]
+pub struct Compiled {
+    code: Vec<Op>,
+    register_b: u64,
+    register_c: u64,
+    instruction_pointer: usize,
+    budget: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Op {
+    Adv(Source),
+    Bxl(u64),
+    Bst(Source),
+    Jnz(usize),
+    Bxc,
+    Out(Source),
+    Bdv(Source),
+    Cdv(Source),
+    Fail(ExecError),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Source {
+    Constant(u64),
+    A,
+    B,
+    C,
+}
+
+impl Source {
+    fn new(operand: ComboOperand) -> Self {
+        match operand.0 {
+            4 => Source::A,
+            5 => Source::B,
+            6 => Source::C,
+            value => Source::Constant(value as u64),
+        }
+    }
+
+    fn value(self, a: u64, b: u64, c: u64) -> u64 {
+        match self {
+            Source::Constant(value) => value,
+            Source::A => a,
+            Source::B => b,
+            Source::C => c,
+        }
+    }
+}
+
+impl Op {
+    fn new(ip: usize, program: &[u8]) -> Self {
+        let Some(&operand) = program.get(ip + 1) else {
+            return Op::Fail(ExecError::TruncatedInstruction { ip });
+        };
+
+        match Instruction::decode(ip, program[ip], operand) {
+            Ok(Instruction::Adv(operand)) => Op::Adv(Source::new(operand)),
+            Ok(Instruction::Bxl(operand)) => Op::Bxl(operand.value()),
+            Ok(Instruction::Bst(operand)) => Op::Bst(Source::new(operand)),
+            Ok(Instruction::Jnz(operand)) => Op::Jnz(operand.value() as usize),
+            Ok(Instruction::Bxc) => Op::Bxc,
+            Ok(Instruction::Out(operand)) => Op::Out(Source::new(operand)),
+            Ok(Instruction::Bdv(operand)) => Op::Bdv(Source::new(operand)),
+            Ok(Instruction::Cdv(operand)) => Op::Cdv(Source::new(operand)),
+            Err(error) => Op::Fail(error),
+        }
+    }
+}
+
+impl Computer {
+    /// Decodes the program, keeping B, C, the instruction pointer and the budget.
+    pub fn compile(&self) -> Compiled {
+        Compiled {
+            code: (0..self.program.len())
+                .map(|ip| Op::new(ip, &self.program))
+                .collect(),
+            register_b: self.register_b,
+            register_c: self.register_c,
+            instruction_pointer: self.instruction_pointer,
+            budget: self.budget,
+        }
+    }
+}
+
+impl Compiled {
+    /// Runs with `register_a`, replacing `output`'s contents with what the
+    /// program prints. Fails exactly where `Computer::run` would.
+    pub fn run(&self, register_a: u64, output: &mut Vec<u8>) -> Result<(), ExecError> {
+        let (mut a, mut b, mut c) = (register_a, self.register_b, self.register_c);
+        let mut ip = self.instruction_pointer;
+        let mut steps = 0;
+        output.clear();
+
+        while let Some(&op) = self.code.get(ip) {
+            let truncated = matches!(op, Op::Fail(ExecError::TruncatedInstruction { .. }));
+            if steps >= self.budget && !truncated {
+                return Err(ExecError::StepLimitExceeded {
+                    ip,
+                    budget: self.budget,
+                });
+            }
+            steps += 1;
+
+            match op {
+                Op::Adv(source) => a = divide(a, source.value(a, b, c)),
+                Op::Bxl(value) => b ^= value,
+                Op::Bst(source) => b = source.value(a, b, c) & 0b111,
+                Op::Jnz(target) if a != 0 => {
+                    ip = target;
+                    continue;
+                }
+                Op::Jnz(_) => {}
+                Op::Bxc => b ^= c,
+                Op::Out(source) => output.push((source.value(a, b, c) & 0b111) as u8),
+                Op::Bdv(source) => b = divide(a, source.value(a, b, c)),
+                Op::Cdv(source) => c = divide(a, source.value(a, b, c)),
+                Op::Fail(error) => return Err(error),
+            }
+
+            ip += 2;
+        }
+
+        Ok(())
+    }
+
+    /// Runs with `register_a` into a fresh buffer.
+    pub fn output(&self, register_a: u64) -> Result<Vec<u8>, ExecError> {
+        let mut output = Vec::new();
+        self.run(register_a, &mut output)?;
+        Ok(output)
+    }
+
+    /// Runs every A in `values` on up to `threads` threads, streaming each
+    /// result to `f` in increasing order of A. Stops early once `f` breaks,
+    /// returning what it broke with.
+    pub fn batch<T>(
+        &self,
+        values: Range<u64>,
+        threads: usize,
+        mut f: impl FnMut(u64, Result<&[u8], ExecError>) -> ControlFlow<T>,
+    ) -> Option<T> {
+        if threads <= 1 {
+            let mut output = Vec::new();
+
+            for register_a in values {
+                let result = self.run(register_a, &mut output);
+                if let ControlFlow::Break(value) = f(register_a, result.map(|_| &output[..])) {
+                    return Some(value);
+                }
+            }
+
+            return None;
+        }
+
+        let next = AtomicU64::new(values.start);
+
+        thread::scope(|scope| {
+            // bounded, so fast workers can't run arbitrarily far ahead of `f`
+            let (sender, receiver) = mpsc::sync_channel(threads * 2);
+
+            for _ in 0..threads {
+                let sender = sender.clone();
+                let (next, end) = (&next, values.end);
+
+                scope.spawn(move || loop {
+                    // claim the next chunk, stopping at `end` rather than
+                    // wrapping round past u64::MAX
+                    let claimed =
+                        next.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |start| {
+                            (start < end).then(|| start.checked_add(CHUNK).unwrap_or(end).min(end))
+                        });
+                    let Ok(start) = claimed else {
+                        return;
+                    };
+
+                    let chunk = self.run_chunk(start..end.min(start.saturating_add(CHUNK)));
+                    // the receiver is gone once `f` has broken
+                    if sender.send(chunk).is_err() {
+                        return;
+                    }
+                });
+            }
+            drop(sender);
+
+            let mut pending = BTreeMap::new();
+            let mut expected = values.start;
+
+            for chunk in receiver {
+                pending.insert(chunk.start, chunk);
+
+                while let Some(chunk) = pending.remove(&expected) {
+                    if let ControlFlow::Break(value) = chunk.stream(&mut f) {
+                        return Some(value);
+                    }
+                    expected = chunk.end;
+                }
+            }
+
+            None
+        })
+    }
+
+    fn run_chunk(&self, values: Range<u64>) -> Chunk {
+        let mut chunk = Chunk {
+            start: values.start,
+            end: values.end,
+            bytes: Vec::new(),
+            results: Vec::with_capacity((values.end - values.start) as usize),
+        };
+        let mut output = Vec::new();
+
+        for register_a in values {
+            let result = self.run(register_a, &mut output).map(|_| {
+                chunk.bytes.extend_from_slice(&output);
+                chunk.bytes.len()
+            });
+            chunk.results.push(result);
+        }
+
+        chunk
+    }
+}
+
+/// The outputs for a run of consecutive A values, packed into one buffer.
+struct Chunk {
+    start: u64,
+    end: u64,
+    bytes: Vec<u8>,
+    /// Where each value's output ends in `bytes`, or why it failed.
+    results: Vec<Result<usize, ExecError>>,
+}
+
+impl Chunk {
+    fn stream<T>(
+        &self,
+        f: &mut impl FnMut(u64, Result<&[u8], ExecError>) -> ControlFlow<T>,
+    ) -> ControlFlow<T> {
+        let mut begin = 0;
+
+        for (register_a, result) in (self.start..self.end).zip(&self.results) {
+            let result = (*result).map(|end| {
+                let output = &self.bytes[begin..end];
+                begin = end;
+                output
+            });
+            f(register_a, result)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use common::parse_file;
+
+    use super::*;
+    use crate::Day17;
+
+    #[test]
+    fn matches_the_interpreter() -> Result<()> {
+        let computer = parse_file::<Day17>("example.txt")?;
+        let compiled = computer.compile();
+
+        assert_eq!(
+            compiled.output(computer.register_a),
+            Ok(computer.clone().run()?.output)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn batches_stream_in_order() -> Result<()> {
+        let computer = parse_file::<Day17>("example_2.txt")?;
+        let compiled = computer.compile();
+
+        for threads in [1, 4] {
+            let mut expected = 0;
+            let quine = compiled.batch(0..1 << 20, threads, |register_a, output| {
+                assert_eq!(register_a, expected);
+                expected += 1;
+
+                if output == Ok(computer.program()) {
+                    ControlFlow::Break(register_a)
+                } else {
+                    ControlFlow::Continue(())
+                }
+            });
+
+            assert_eq!(quine, Some(117440));
+        }
+
+        let mut count = 0;
+        let none = compiled.batch(10..10_000, 3, |_, _| {
+            count += 1;
+            ControlFlow::<()>::Continue(())
+        });
+        assert_eq!((none, count), (None, 9_990));
+
+        // chunks near the top of the range can't wrap round to the start
+        let mut count = 0;
+        let none = compiled.batch(u64::MAX - 10_000..u64::MAX, 3, |_, _| {
+            count += 1;
+            ControlFlow::<()>::Continue(())
+        });
+        assert_eq!((none, count), (None, 10_000));
+        Ok(())
+    }
+}
@@ -0,0 +1,338 @@
+use std::collections::BTreeSet;
+use std::fmt::{self, Display, Write};
+use std::str::FromStr;
+
+use anyhow::{bail, Error, Result};
+use serde::Serialize;
+
+use crate::{Computer, ExecError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Register {
+    A,
+    B,
+    C,
+}
+
+impl Register {
+    pub const ALL: [Register; 3] = [Register::A, Register::B, Register::C];
+}
+
+impl FromStr for Register {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "a" | "A" => Ok(Register::A),
+            "b" | "B" => Ok(Register::B),
+            "c" | "C" => Ok(Register::C),
+            _ => bail!("Unknown register {:?}, expected a, b or c", s),
+        }
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Register::A => write!(f, "a"),
+            Register::B => write!(f, "b"),
+            Register::C => write!(f, "c"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl Registers {
+    pub fn get(&self, register: Register) -> u64 {
+        match register {
+            Register::A => self.a,
+            Register::B => self.b,
+            Register::C => self.c,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Change {
+    pub register: Register,
+    pub from: u64,
+    pub to: u64,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.register, self.from, self.to)
+    }
+}
+
+/// One executed instruction and what it did.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Step {
+    pub ip: usize,
+    pub instruction: String,
+    pub changes: Vec<Change>,
+    pub output: Option<u8>,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut line = format!("{:>3}: {:<6}", self.ip, self.instruction);
+
+        for change in &self.changes {
+            write!(line, "  {}", change)?;
+        }
+
+        if let Some(output) = self.output {
+            write!(line, "  out {}", output)?;
+        }
+
+        f.write_str(line.trim_end())
+    }
+}
+
+impl Computer {
+    pub fn registers(&self) -> Registers {
+        Registers {
+            a: self.register_a,
+            b: self.register_b,
+            c: self.register_c,
+        }
+    }
+
+    pub fn set_register(&mut self, register: Register, value: u64) {
+        match register {
+            Register::A => self.register_a = value,
+            Register::B => self.register_b = value,
+            Register::C => self.register_c = value,
+        }
+    }
+
+    pub fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
+    }
+
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.instruction_pointer >= self.program.len()
+    }
+
+    /// Executes a single instruction, or returns `None` once the program has halted.
+    pub fn step(&mut self) -> Result<Option<Step>, ExecError> {
+        let ip = self.instruction_pointer;
+        let Some(instruction) = self.next_instruction()? else {
+            return Ok(None);
+        };
+        let text = instruction.to_string();
+        let before = self.registers();
+        let printed = self.output.len();
+
+        self.execute_instruction(instruction);
+
+        let after = self.registers();
+        let changes = Register::ALL
+            .into_iter()
+            .filter(|&register| before.get(register) != after.get(register))
+            .map(|register| Change {
+                register,
+                from: before.get(register),
+                to: after.get(register),
+            })
+            .collect();
+
+        Ok(Some(Step {
+            ip,
+            instruction: text,
+            changes,
+            output: self.output.get(printed).copied(),
+        }))
+    }
+}
+
+/// Why `Debugger::resume` stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    Halted,
+    Breakpoint(usize),
+    Watch(Change),
+    Error(ExecError),
+}
+
+impl Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stop::Halted => write!(f, "halted"),
+            Stop::Breakpoint(ip) => write!(f, "breakpoint at {}", ip),
+            Stop::Watch(change) => write!(f, "watch {}", change),
+            Stop::Error(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// Runs a `Computer` with breakpoints on instruction pointers and watches on
+/// registers, recording every step it takes.
+#[derive(Clone, Debug)]
+pub struct Debugger {
+    computer: Computer,
+    breakpoints: BTreeSet<usize>,
+    watches: BTreeSet<Register>,
+    trace: Vec<Step>,
+}
+
+impl Debugger {
+    pub fn new(computer: Computer) -> Self {
+        Self {
+            computer,
+            breakpoints: BTreeSet::new(),
+            watches: BTreeSet::new(),
+            trace: Vec::new(),
+        }
+    }
+
+    pub fn computer(&self) -> &Computer {
+        &self.computer
+    }
+
+    pub fn trace(&self) -> &[Step] {
+        &self.trace
+    }
+
+    pub fn breakpoints(&self) -> &BTreeSet<usize> {
+        &self.breakpoints
+    }
+
+    pub fn set_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.insert(ip);
+    }
+
+    pub fn clear_breakpoint(&mut self, ip: usize) -> bool {
+        self.breakpoints.remove(&ip)
+    }
+
+    pub fn watch(&mut self, register: Register) {
+        self.watches.insert(register);
+    }
+
+    pub fn unwatch(&mut self, register: Register) -> bool {
+        self.watches.remove(&register)
+    }
+
+    pub fn step(&mut self) -> Result<Option<&Step>, ExecError> {
+        let Some(step) = self.computer.step()? else {
+            return Ok(None);
+        };
+        self.trace.push(step);
+        Ok(self.trace.last())
+    }
+
+    /// Runs at least one instruction, then stops before a breakpoint, after a
+    /// watched register changes, or when the program halts or fails.
+    pub fn resume(&mut self) -> Stop {
+        loop {
+            let step = match self.computer.step() {
+                Ok(Some(step)) => step,
+                Ok(None) => return Stop::Halted,
+                Err(error) => return Stop::Error(error),
+            };
+
+            let watched = step
+                .changes
+                .iter()
+                .find(|change| self.watches.contains(&change.register))
+                .copied();
+            self.trace.push(step);
+
+            if let Some(change) = watched {
+                return Stop::Watch(change);
+            }
+
+            let ip = self.computer.instruction_pointer;
+            if self.breakpoints.contains(&ip) && !self.computer.is_halted() {
+                return Stop::Breakpoint(ip);
+            }
+        }
+    }
+}
+
+/// One line per step, as `Step` displays.
+pub fn trace_text(trace: &[Step]) -> String {
+    let mut text = String::new();
+
+    for step in trace {
+        writeln!(text, "{}", step).unwrap();
+    }
+
+    text
+}
+
+pub fn trace_json(trace: &[Step]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(trace)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse_file;
+
+    use super::*;
+    use crate::Day17;
+
+    #[test]
+    fn stops_at_breakpoints_and_watches() -> Result<()> {
+        let mut debugger = Debugger::new(parse_file::<Day17>("example.txt")?);
+        debugger.set_breakpoint(4);
+
+        assert_eq!(debugger.resume(), Stop::Breakpoint(4));
+        assert_eq!(debugger.computer().output(), [4]);
+        assert_eq!(debugger.trace().len(), 2);
+
+        debugger.clear_breakpoint(4);
+        debugger.watch(Register::A);
+        let stop = debugger.resume();
+        assert_eq!(
+            stop,
+            Stop::Watch(Change {
+                register: Register::A,
+                from: 364,
+                to: 182
+            })
+        );
+
+        debugger.unwatch(Register::A);
+        assert_eq!(debugger.resume(), Stop::Halted);
+        assert!(debugger.step()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn renders_the_trace() -> Result<()> {
+        let mut debugger = Debugger::new(parse_file::<Day17>("example.txt")?);
+        debugger.step()?;
+        debugger.step()?;
+
+        assert_eq!(
+            trace_text(debugger.trace()),
+            "  0: adv 1   a: 729 -> 364\n  2: out a   out 4\n"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&trace_json(debugger.trace())?)?;
+        assert_eq!(
+            json[0],
+            serde_json::json!({
+                "ip": 0,
+                "instruction": "adv 1",
+                "changes": [{ "register": "a", "from": 729, "to": 364 }],
+                "output": null
+            })
+        );
+        Ok(())
+    }
+}
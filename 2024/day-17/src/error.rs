@@ -0,0 +1,39 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// Why a program stopped before running off the end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExecError {
+    /// An opcode outside 0-7.
+    InvalidOpcode { ip: usize, opcode: u8 },
+    /// An operand outside 0-7, which no three-bit instruction can hold.
+    InvalidOperand { ip: usize, operand: u8 },
+    /// Combo operand 7, which is reserved.
+    ReservedOperand { ip: usize },
+    /// An opcode in the last byte of the program, with no operand after it.
+    TruncatedInstruction { ip: usize },
+    /// The step budget ran out, most likely in a loop that never ends.
+    StepLimitExceeded { ip: usize, budget: u64 },
+}
+
+impl Display for ExecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ExecError::InvalidOpcode { ip, opcode } => {
+                write!(f, "Invalid opcode {} at {}", opcode, ip)
+            }
+            ExecError::InvalidOperand { ip, operand } => {
+                write!(f, "Invalid operand {} at {}", operand, ip)
+            }
+            ExecError::ReservedOperand { ip } => write!(f, "Reserved combo operand 7 at {}", ip),
+            ExecError::TruncatedInstruction { ip } => {
+                write!(f, "Instruction at {} is missing its operand", ip)
+            }
+            ExecError::StepLimitExceeded { ip, budget } => {
+                write!(f, "Still running at {} after {} steps", ip, budget)
+            }
+        }
+    }
+}
+
+impl Error for ExecError {}
@@ -0,0 +1,642 @@
+use std::fmt::{Debug, Display};
+
+use anyhow::Result;
+use common::{Params, Parser, Solution};
+
+mod asm;
+mod compile;
+mod debug;
+mod error;
+mod quine;
+
+pub use asm::{assemble, disassemble};
+pub use compile::Compiled;
+pub use debug::{trace_json, trace_text, Change, Debugger, Register, Registers, Step, Stop};
+pub use error::ExecError;
+pub use quine::{find_quine, QuineError};
+
+pub type Input = Computer;
+
+/// How many instructions a program may execute before it's assumed to loop forever.
+pub const BUDGET: u64 = 1_000_000;
+
+#[derive(Clone, Debug)]
+pub struct Computer {
+    register_a: u64,
+    register_b: u64,
+    register_c: u64,
+    instruction_pointer: usize,
+    program: Vec<u8>,
+    output: Vec<u8>,
+    steps: u64,
+    budget: u64,
+}
+
+impl Default for Computer {
+    fn default() -> Self {
+        Self {
+            register_a: 0,
+            register_b: 0,
+            register_c: 0,
+            instruction_pointer: 0,
+            program: Vec::new(),
+            output: Vec::new(),
+            steps: 0,
+            budget: BUDGET,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Adv(ComboOperand),
+    Bxl(LiteralOperand),
+    Bst(ComboOperand),
+    Jnz(LiteralOperand),
+    Bxc,
+    Out(ComboOperand),
+    Bdv(ComboOperand),
+    Cdv(ComboOperand),
+}
+
+#[derive(Debug)]
+struct LiteralOperand(u8);
+impl LiteralOperand {
+    fn value(&self) -> u64 {
+        self.0 as u64
+    }
+}
+
+#[derive(Debug)]
+struct ComboOperand(u8);
+impl ComboOperand {
+    fn value(&self, computer: &Computer) -> u64 {
+        match self.0 {
+            0 => 0u64,
+            1 => 1u64,
+            2 => 2u64,
+            3 => 3u64,
+            4 => computer.register_a,
+            5 => computer.register_b,
+            6 => computer.register_c,
+            _ => unreachable!("decode rejects reserved operands"),
+        }
+    }
+}
+
+impl Instruction {
+    /// Decodes the instruction at `ip`, rejecting anything `execute_instruction` can't run.
+    fn decode(ip: usize, opcode: u8, operand: u8) -> Result<Instruction, ExecError> {
+        if operand > 7 {
+            return Err(ExecError::InvalidOperand { ip, operand });
+        }
+
+        let instruction = match opcode {
+            0 => Instruction::Adv(ComboOperand(operand)),
+            1 => Instruction::Bxl(LiteralOperand(operand)),
+            2 => Instruction::Bst(ComboOperand(operand)),
+            3 => Instruction::Jnz(LiteralOperand(operand)),
+            4 => Instruction::Bxc,
+            5 => Instruction::Out(ComboOperand(operand)),
+            6 => Instruction::Bdv(ComboOperand(operand)),
+            7 => Instruction::Cdv(ComboOperand(operand)),
+            _ => return Err(ExecError::InvalidOpcode { ip, opcode }),
+        };
+
+        match instruction {
+            Instruction::Adv(ComboOperand(7))
+            | Instruction::Bst(ComboOperand(7))
+            | Instruction::Out(ComboOperand(7))
+            | Instruction::Bdv(ComboOperand(7))
+            | Instruction::Cdv(ComboOperand(7)) => Err(ExecError::ReservedOperand { ip }),
+            instruction => Ok(instruction),
+        }
+    }
+
+    fn opcode(&self) -> u8 {
+        match self {
+            Instruction::Adv(_) => 0,
+            Instruction::Bxl(_) => 1,
+            Instruction::Bst(_) => 2,
+            Instruction::Jnz(_) => 3,
+            Instruction::Bxc => 4,
+            Instruction::Out(_) => 5,
+            Instruction::Bdv(_) => 6,
+            Instruction::Cdv(_) => 7,
+        }
+    }
+}
+
+impl Computer {
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    /// Limits how many instructions the program may execute in total.
+    pub fn set_budget(&mut self, budget: u64) {
+        self.budget = budget;
+    }
+
+    /// The instruction to execute next, or `None` once the program has halted.
+    fn next_instruction(&self) -> Result<Option<Instruction>, ExecError> {
+        let ip = self.instruction_pointer;
+
+        let Some(&opcode) = self.program.get(ip) else {
+            return Ok(None);
+        };
+        let Some(&operand) = self.program.get(ip + 1) else {
+            return Err(ExecError::TruncatedInstruction { ip });
+        };
+
+        if self.steps >= self.budget {
+            return Err(ExecError::StepLimitExceeded {
+                ip,
+                budget: self.budget,
+            });
+        }
+
+        Instruction::decode(ip, opcode, operand).map(Some)
+    }
+
+    fn execute_instruction(&mut self, instruction: Instruction) {
+        self.steps += 1;
+
+        match instruction {
+            Instruction::Adv(operand) => {
+                let value = operand.value(self);
+                self.register_a = divide(self.register_a, value);
+                self.instruction_pointer += 2;
+            }
+            Instruction::Bxl(operand) => {
+                let value = operand.value();
+                self.register_b ^= value;
+                self.instruction_pointer += 2;
+            }
+            Instruction::Bst(operand) => {
+                let value = operand.value(self);
+                self.register_b = value & 0b111;
+                self.instruction_pointer += 2;
+            }
+            Instruction::Jnz(operand) => {
+                if self.register_a != 0 {
+                    self.instruction_pointer = operand.value() as usize;
+                } else {
+                    self.instruction_pointer += 2;
+                }
+            }
+            Instruction::Bxc => {
+                self.register_b ^= self.register_c;
+                self.instruction_pointer += 2;
+            }
+            Instruction::Out(operand) => {
+                let value = operand.value(self);
+                self.output.push((value & 0b111) as u8);
+                self.instruction_pointer += 2;
+            }
+            Instruction::Bdv(operand) => {
+                let value = operand.value(self);
+                self.register_b = divide(self.register_a, value);
+                self.instruction_pointer += 2;
+            }
+            Instruction::Cdv(operand) => {
+                let value = operand.value(self);
+                self.register_c = divide(self.register_a, value);
+                self.instruction_pointer += 2;
+            }
+        }
+    }
+
+    /// Runs until the program halts, or fails within its step budget.
+    pub fn run(mut self) -> Result<Self, ExecError> {
+        while let Some(instruction) = self.next_instruction()? {
+            self.execute_instruction(instruction);
+        }
+
+        Ok(self)
+    }
+
+    fn print_output(&self) -> String {
+        self.output
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// `numerator / 2^exponent`, which is zero once the exponent reaches 64.
+fn divide(numerator: u64, exponent: u64) -> u64 {
+    u32::try_from(exponent)
+        .ok()
+        .and_then(|exponent| numerator.checked_shr(exponent))
+        .unwrap_or(0)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Input;
+
+    const PARAMS: &'static [&'static str] = &["budget"];
+
+    fn parse(input: &str) -> Result<Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input> {
+        let mut parser = Parser::new(input);
+
+        let mut registers = parser.expect_section("registers")?;
+        let register_a = registers.field("Register A:")?;
+        registers.newline()?;
+        let register_b = registers.field("Register B:")?;
+        registers.newline()?;
+        let register_c = registers.field("Register C:")?;
+        registers.end()?;
+
+        let mut section = parser.expect_section("program")?;
+        section.literal("Program:")?;
+        section.spaces();
+        let program = section.separated(",", |p| p.number())?;
+        section.end()?;
+        parser.end()?;
+
+        Ok(Computer {
+            register_a,
+            register_b,
+            register_c,
+            instruction_pointer: 0,
+            program,
+            output: Vec::new(),
+            steps: 0,
+            budget: params.get("budget", BUDGET)?,
+        })
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(input.clone().run()?.print_output())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Ok(find_quine(input)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse_file;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_adv() -> Result<()> {
+        let computer = Computer {
+            register_a: 0b00111,
+            register_b: 0b01111,
+            register_c: 0x10111,
+            instruction_pointer: 0,
+            program: vec![0, 1],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+        assert_eq!(computer.register_a, 0b0011);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bxl() -> Result<()> {
+        let computer = Computer {
+            register_a: 0,
+            register_b: 5,
+            register_c: 0,
+            instruction_pointer: 0,
+            program: vec![1, 2],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+        assert_eq!(computer.register_b, 7);
+        let computer = Computer {
+            register_a: 0,
+            register_b: 1,
+            register_c: 0,
+            instruction_pointer: 0,
+            program: vec![1, 1],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+        assert_eq!(computer.register_b, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bst() -> Result<()> {
+        let computer = Computer {
+            register_a: 0b0101010101,
+            register_b: 0,
+            register_c: 9,
+            instruction_pointer: 0,
+            program: vec![2, 4],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+        assert_eq!(computer.register_b, 0b101);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_jnz() -> Result<()> {
+        let computer = Computer {
+            register_a: 0,
+            register_b: 0,
+            register_c: 0,
+            instruction_pointer: 0,
+            program: vec![3, 4, 2, 1],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+        assert_eq!(computer.register_b, 1);
+
+        let computer = Computer {
+            register_a: 1,
+            register_b: 0,
+            register_c: 0,
+            instruction_pointer: 0,
+            program: vec![3, 4, 2, 1],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+        assert_eq!(computer.register_b, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bxc() -> Result<()> {
+        let computer = Computer {
+            register_a: 0,
+            register_b: 0b101,
+            register_c: 0b110,
+            instruction_pointer: 0,
+            program: vec![4, 0],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+        assert_eq!(computer.register_b, 0b011);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_out() -> Result<()> {
+        let computer = Computer {
+            register_a: 1,
+            register_b: 0,
+            register_c: 0,
+            instruction_pointer: 0,
+            program: vec![5, 4],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+        assert_eq!(computer.output, vec![1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bdv() -> Result<()> {
+        let computer = Computer {
+            register_a: 0b00111,
+            register_b: 0b01111,
+            register_c: 0x10111,
+            instruction_pointer: 0,
+            program: vec![6, 1],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+        assert_eq!(computer.register_b, 0b0011);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cdv() -> Result<()> {
+        let computer = Computer {
+            register_a: 0b00111,
+            register_b: 0b01111,
+            register_c: 0x10111,
+            instruction_pointer: 0,
+            program: vec![7, 1],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+        assert_eq!(computer.register_c, 0b0011);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_1() -> Result<()> {
+        let mut computer = Computer {
+            register_a: 0,
+            register_b: 0,
+            register_c: 9,
+            instruction_pointer: 0,
+            program: vec![2, 6],
+            output: Vec::new(),
+            ..Computer::default()
+        };
+
+        let instruction = computer.next_instruction()?.unwrap();
+        computer.execute_instruction(instruction);
+
+        assert_eq!(computer.register_b, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        let computer = Computer {
+            register_a: 10,
+            register_b: 0,
+            register_c: 0,
+            instruction_pointer: 0,
+            program: vec![5, 0, 5, 1, 5, 4],
+            output: Vec::new(),
+            ..Computer::default()
+        };
+
+        let output = computer.run()?.print_output();
+        assert_eq!(output, "0,1,2");
+        Ok(())
+    }
+
+    #[test]
+    fn example_3() -> Result<()> {
+        let computer = Computer {
+            register_a: 2024,
+            register_b: 0,
+            register_c: 0,
+            instruction_pointer: 0,
+            program: vec![0, 1, 5, 4, 3, 0],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+
+        let output = computer.print_output();
+
+        assert_eq!(computer.register_a, 0);
+        assert_eq!(output, "4,2,5,6,7,7,7,7,3,1,0");
+        Ok(())
+    }
+
+    #[test]
+    fn example_4() -> Result<()> {
+        let computer = Computer {
+            register_a: 0,
+            register_b: 29,
+            register_c: 0,
+            instruction_pointer: 0,
+            program: vec![1, 7],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+
+        assert_eq!(computer.register_b, 26);
+        Ok(())
+    }
+
+    #[test]
+    fn example_5() -> Result<()> {
+        let computer = Computer {
+            register_a: 0,
+            register_b: 2024,
+            register_c: 43690,
+            instruction_pointer: 0,
+            program: vec![4, 0],
+            output: Vec::new(),
+            ..Computer::default()
+        }
+        .run()?;
+
+        assert_eq!(computer.register_b, 44354);
+        Ok(())
+    }
+
+    #[test]
+    fn example_main() -> Result<()> {
+        let output = parse_file::<Day17>("example.txt")?.run()?.print_output();
+
+        assert_eq!(&output, "4,6,3,5,6,3,5,2,1,0");
+        Ok(())
+    }
+
+    fn with_program(program: Vec<u8>) -> Computer {
+        Computer {
+            program,
+            ..Computer::default()
+        }
+    }
+
+    #[test]
+    fn reports_exec_errors() {
+        let run = |program: Vec<u8>| with_program(program).run().unwrap_err();
+
+        assert_eq!(
+            run(vec![8, 0]),
+            ExecError::InvalidOpcode { ip: 0, opcode: 8 }
+        );
+        assert_eq!(
+            run(vec![1, 0, 0, 9]),
+            ExecError::InvalidOperand { ip: 2, operand: 9 }
+        );
+        assert_eq!(run(vec![5, 7]), ExecError::ReservedOperand { ip: 0 });
+        assert_eq!(
+            run(vec![1, 2, 5]),
+            ExecError::TruncatedInstruction { ip: 2 }
+        );
+
+        let mut computer = with_program(vec![3, 0]);
+        computer.register_a = 1;
+        computer.set_budget(100);
+        assert_eq!(
+            computer.run().unwrap_err(),
+            ExecError::StepLimitExceeded { ip: 0, budget: 100 }
+        );
+    }
+
+    #[test]
+    fn fails_when_the_program_does() {
+        let error = Day17::part_one(&with_program(vec![8, 0])).err().unwrap();
+        assert!(error.to_string().contains("Invalid opcode"), "{}", error);
+    }
+
+    #[test]
+    fn fails_when_there_is_no_quine() {
+        // prints 0 until A runs out, but the program starts with 5
+        let error = Day17::part_two(&with_program(vec![5, 0, 0, 1, 3, 0]))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "No value of A makes the program print itself"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn random_programs_halt_or_fail_cleanly(
+            program in prop::collection::vec(0..10u8, 0..24),
+            registers in any::<(u64, u64, u64)>(),
+        ) {
+            let mut computer = with_program(program.clone());
+            (computer.register_a, computer.register_b, computer.register_c) = registers;
+            computer.set_budget(1_000);
+
+            let mut debugger = Debugger::new(computer.clone());
+            let stop = debugger.resume();
+            let compiled = computer.compile().output(computer.register_a);
+
+            match computer.run() {
+                Ok(computer) => {
+                    prop_assert!(computer.is_halted());
+                    prop_assert!(computer.output.iter().all(|&value| value < 8));
+                    prop_assert_eq!(stop, Stop::Halted);
+                    prop_assert_eq!(debugger.computer().output(), &computer.output[..]);
+                    prop_assert_eq!(compiled, Ok(computer.output));
+                }
+                Err(error) => {
+                    prop_assert!(computer_fails(&program, error));
+                    prop_assert_eq!(stop, Stop::Error(error));
+                    prop_assert_eq!(compiled, Err(error));
+                }
+            }
+        }
+    }
+
+    /// Whether `error` is one `program` can actually produce.
+    fn computer_fails(program: &[u8], error: ExecError) -> bool {
+        match error {
+            ExecError::InvalidOpcode { ip, opcode } => program[ip] == opcode && opcode > 7,
+            ExecError::InvalidOperand { ip, operand } => program[ip + 1] == operand && operand > 7,
+            ExecError::ReservedOperand { ip } => program[ip + 1] == 7,
+            ExecError::TruncatedInstruction { ip } => ip == program.len() - 1,
+            ExecError::StepLimitExceeded { budget, .. } => budget == 1_000,
+        }
+    }
+}
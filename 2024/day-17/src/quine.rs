@@ -0,0 +1,581 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::{ComboOperand, Computer, ExecError, Instruction, LiteralOperand};
+
+/// Why `find_quine` didn't find an A.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuineError {
+    /// No 64-bit A makes the program print itself.
+    NoSolution,
+    /// Some executions ran out of step budget before halting, so they might
+    /// still have led to a smaller A.
+    Incomplete(ExecError),
+}
+
+impl Display for QuineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QuineError::NoSolution => write!(f, "No value of A makes the program print itself"),
+            QuineError::Incomplete(error) => write!(f, "Search incomplete: {}", error),
+        }
+    }
+}
+
+impl Error for QuineError {}
+
+/// The smallest A for which the program prints a copy of itself.
+///
+/// Every instruction is linear over GF(2) once shift amounts are known, so the
+/// program runs on A's 64 bits symbolically: each register bit is an XOR of
+/// A's bits, every output adds three equations, and the search only branches
+/// when a shift amount or a `jnz` depends on bits not yet pinned down. Works
+/// for any program, as long as the search fits in the computer's step budget,
+/// which here counts instructions across every path explored.
+pub fn find_quine(computer: &Computer) -> Result<u64, QuineError> {
+    let mut search = Search {
+        program: &computer.program,
+        budget: computer.budget,
+        steps: 0,
+        seen: HashSet::new(),
+        best: None,
+        cut: None,
+    };
+
+    search.explore(Path {
+        a: Word::register_a(),
+        b: Word::constant(computer.register_b),
+        c: Word::constant(computer.register_c),
+        ip: computer.instruction_pointer,
+        outputs: 0,
+        system: System::default(),
+        nonzero: Vec::new(),
+    });
+
+    match (search.best, search.cut) {
+        (Some(best), Some((bound, _))) if best <= bound => Ok(best),
+        (_, Some((_, error))) => Err(QuineError::Incomplete(error)),
+        (Some(best), None) => Ok(best),
+        (None, None) => Err(QuineError::NoSolution),
+    }
+}
+
+/// An affine function of A's starting bits: the parity of the bits in `vars`,
+/// XORed with `constant`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+struct Form {
+    vars: u64,
+    constant: bool,
+}
+
+impl Form {
+    fn constant(constant: bool) -> Self {
+        Self { vars: 0, constant }
+    }
+
+    fn xor(self, other: Form) -> Self {
+        Self {
+            vars: self.vars ^ other.vars,
+            constant: self.constant ^ other.constant,
+        }
+    }
+
+    fn eval(self, a: u64) -> bool {
+        ((self.vars & a).count_ones() % 2 == 1) != self.constant
+    }
+}
+
+/// A register, one `Form` per bit, least significant first.
+#[derive(Clone, Copy, Debug, Hash)]
+struct Word([Form; 64]);
+
+impl Word {
+    fn constant(value: u64) -> Self {
+        Self(std::array::from_fn(|bit| {
+            Form::constant(value >> bit & 1 == 1)
+        }))
+    }
+
+    fn register_a() -> Self {
+        Self(std::array::from_fn(|bit| Form {
+            vars: 1 << bit,
+            constant: false,
+        }))
+    }
+
+    fn shr(&self, amount: u32) -> Self {
+        Self(std::array::from_fn(|bit| {
+            self.0
+                .get(bit + amount as usize)
+                .copied()
+                .unwrap_or_default()
+        }))
+    }
+
+    fn xor(&self, other: &Word) -> Self {
+        Self(std::array::from_fn(|bit| self.0[bit].xor(other.0[bit])))
+    }
+
+    fn low_bits(&self) -> Self {
+        Self(std::array::from_fn(|bit| {
+            if bit < 3 {
+                self.0[bit]
+            } else {
+                Form::default()
+            }
+        }))
+    }
+}
+
+/// Equations over A's bits in echelon form: `rows[p]`, when present, has `p`
+/// as its lowest variable and says the parity of its `vars` is its `constant`.
+#[derive(Clone, Debug)]
+struct System {
+    rows: [Option<Form>; 64],
+}
+
+impl Default for System {
+    fn default() -> Self {
+        Self { rows: [None; 64] }
+    }
+}
+
+impl System {
+    /// Rewrites `form` in terms of the variables no row pins down.
+    fn reduce(&self, mut form: Form) -> Form {
+        for bit in 0..64 {
+            if form.vars >> bit & 1 == 1 {
+                if let Some(row) = self.rows[bit] {
+                    form = form.xor(row);
+                }
+            }
+        }
+
+        form
+    }
+
+    /// The value of `form` if the equations already determine it.
+    fn value(&self, form: Form) -> Option<bool> {
+        let form = self.reduce(form);
+        (form.vars == 0).then_some(form.constant)
+    }
+
+    /// Adds `form == value`, returning false if that contradicts the system.
+    fn assume(&mut self, form: Form, value: bool) -> bool {
+        let row = self.reduce(form.xor(Form::constant(value)));
+
+        if row.vars == 0 {
+            return !row.constant;
+        }
+
+        self.rows[row.vars.trailing_zeros() as usize] = Some(row);
+        true
+    }
+
+    /// The smallest A satisfying every equation: each row's lowest variable
+    /// depends only on higher ones, so free bits can be cleared from the top down.
+    fn minimum(&self) -> u64 {
+        let mut a = 0;
+
+        for bit in (0..64).rev() {
+            if let Some(row) = self.rows[bit] {
+                let others = Form {
+                    vars: row.vars & !(1 << bit),
+                    constant: row.constant,
+                };
+
+                if others.eval(a) {
+                    a |= 1 << bit;
+                }
+            }
+        }
+
+        a
+    }
+
+    /// The smallest A satisfying every equation and leaving at least one form
+    /// of each of `nonzero` set.
+    fn minimum_with(&self, nonzero: &[Vec<Form>]) -> Option<u64> {
+        let a = self.minimum();
+
+        let Some(unmet) = nonzero
+            .iter()
+            .find(|forms| forms.iter().all(|form| !form.eval(a)))
+        else {
+            return Some(a);
+        };
+
+        unmet
+            .iter()
+            .filter_map(|&form| {
+                let mut system = self.clone();
+                system
+                    .assume(form, true)
+                    .then(|| system.minimum_with(nonzero))
+                    .flatten()
+            })
+            .min()
+    }
+}
+
+/// One way execution can go, given the equations chosen so far.
+#[derive(Clone, Debug)]
+struct Path {
+    a: Word,
+    b: Word,
+    c: Word,
+    ip: usize,
+    outputs: usize,
+    system: System,
+    /// Sets of forms that can't all be zero, from `jnz` jumps and oversized shifts.
+    nonzero: Vec<Vec<Form>>,
+}
+
+impl Path {
+    fn state_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (self.ip, self.outputs, &self.a, &self.b, &self.c).hash(&mut hasher);
+        (&self.system.rows, &self.nonzero).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn combo(&self, operand: &ComboOperand) -> Word {
+        match operand.0 {
+            value @ 0..=3 => Word::constant(value as u64),
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            _ => unreachable!("decode rejects reserved operands"),
+        }
+    }
+
+    /// Adds `form == value`, returning false if the path becomes impossible.
+    fn assume(&mut self, form: Form, value: bool) -> bool {
+        self.system.assume(form, value) && self.check_nonzero()
+    }
+
+    /// Drops satisfied `nonzero` sets, and fails if any set is now all zero.
+    fn check_nonzero(&mut self) -> bool {
+        let system = &self.system;
+        let mut possible = true;
+
+        self.nonzero.retain_mut(|forms| {
+            if forms.iter().any(|&form| system.value(form) == Some(true)) {
+                return false;
+            }
+
+            forms.retain(|&form| system.value(form).is_none());
+            possible &= !forms.is_empty();
+            true
+        });
+
+        possible
+    }
+
+    /// Splits the path on whether `forms` are all zero: the first result
+    /// assumes they are, the second that they aren't.
+    fn split_zero(self, forms: &[Form]) -> (Option<Path>, Option<Path>) {
+        if forms
+            .iter()
+            .any(|&form| self.system.value(form) == Some(true))
+        {
+            return (None, Some(self));
+        }
+
+        let open: Vec<Form> = forms
+            .iter()
+            .copied()
+            .filter(|&form| self.system.value(form).is_none())
+            .collect();
+
+        if open.is_empty() {
+            return (Some(self), None);
+        }
+
+        let mut zero = self.clone();
+        let zero = (open.iter().all(|&form| zero.system.assume(form, false))
+            && zero.check_nonzero())
+        .then_some(zero);
+
+        // a set containing every form of an earlier one adds nothing
+        let mut nonzero = self;
+        if !nonzero
+            .nonzero
+            .iter()
+            .any(|forms| forms.iter().all(|form| open.contains(form)))
+        {
+            nonzero.nonzero.push(open);
+        }
+
+        (zero, Some(nonzero))
+    }
+
+    /// Every way `forms` can be assigned, with the value they spell out.
+    fn split_bits(self, forms: &[Form]) -> Vec<(Path, u64)> {
+        let mut paths = vec![(self, 0)];
+
+        for (bit, &form) in forms.iter().enumerate() {
+            paths = paths
+                .into_iter()
+                .flat_map(|(path, value)| match path.system.value(form) {
+                    Some(known) => vec![(path, value | (known as u64) << bit)],
+                    None => [false, true]
+                        .into_iter()
+                        .filter_map(|known| {
+                            let mut path = path.clone();
+                            path.assume(form, known)
+                                .then_some((path, value | (known as u64) << bit))
+                        })
+                        .collect(),
+                })
+                .collect();
+        }
+
+        paths
+    }
+
+    /// Every concrete shift `amount` can be, with 64 standing for anything that
+    /// empties the register.
+    fn shifts(self, amount: &Word) -> Vec<(Path, u32)> {
+        let (small, large) = self.split_zero(&amount.0[6..]);
+
+        let mut paths: Vec<_> = small
+            .into_iter()
+            .flat_map(|path| path.split_bits(&amount.0[..6]))
+            .map(|(path, amount)| (path, amount as u32))
+            .collect();
+        paths.extend(large.map(|path| (path, 64)));
+
+        paths
+    }
+}
+
+struct Search<'a> {
+    program: &'a [u8],
+    /// Shared by every path, so a search that branches without end still stops.
+    budget: u64,
+    steps: u64,
+    /// Hashes of the states `jnz` has jumped to.
+    seen: HashSet<u64>,
+    best: Option<u64>,
+    /// The smallest A any path cut short by the budget could have led to.
+    cut: Option<(u64, ExecError)>,
+}
+
+impl Search<'_> {
+    /// Depth first, trying each branch's lower bits as zero first.
+    fn explore(&mut self, start: Path) {
+        let mut stack = vec![start];
+
+        while let Some(path) = stack.pop() {
+            if self.best.is_some_and(|best| path.system.minimum() >= best) {
+                continue;
+            }
+
+            stack.extend(self.step(path).into_iter().rev());
+        }
+    }
+
+    /// Executes one instruction, returning every path it can continue on.
+    fn step(&mut self, mut path: Path) -> Vec<Path> {
+        let ip = path.ip;
+
+        let Some(&opcode) = self.program.get(ip) else {
+            self.halt(path);
+            return Vec::new();
+        };
+        let Some(&operand) = self.program.get(ip + 1) else {
+            return Vec::new();
+        };
+
+        if self.steps >= self.budget {
+            let bound = path.system.minimum();
+            let error = ExecError::StepLimitExceeded {
+                ip,
+                budget: self.budget,
+            };
+
+            if self.cut.is_none_or(|(cut, _)| bound < cut) {
+                self.cut = Some((bound, error));
+            }
+            return Vec::new();
+        }
+
+        let Ok(instruction) = Instruction::decode(ip, opcode, operand) else {
+            return Vec::new();
+        };
+
+        self.steps += 1;
+        path.ip += 2;
+
+        match instruction {
+            Instruction::Adv(operand) => {
+                let amount = path.combo(&operand);
+                path.shifts(&amount)
+                    .into_iter()
+                    .map(|(mut path, amount)| {
+                        path.a = path.a.shr(amount);
+                        path
+                    })
+                    .collect()
+            }
+            Instruction::Bdv(operand) => {
+                let amount = path.combo(&operand);
+                path.shifts(&amount)
+                    .into_iter()
+                    .map(|(mut path, amount)| {
+                        path.b = path.a.shr(amount);
+                        path
+                    })
+                    .collect()
+            }
+            Instruction::Cdv(operand) => {
+                let amount = path.combo(&operand);
+                path.shifts(&amount)
+                    .into_iter()
+                    .map(|(mut path, amount)| {
+                        path.c = path.a.shr(amount);
+                        path
+                    })
+                    .collect()
+            }
+            Instruction::Bxl(LiteralOperand(value)) => {
+                path.b = path.b.xor(&Word::constant(value as u64));
+                vec![path]
+            }
+            Instruction::Bst(operand) => {
+                path.b = path.combo(&operand).low_bits();
+                vec![path]
+            }
+            Instruction::Jnz(LiteralOperand(target)) => {
+                let a = path.a;
+                let (zero, nonzero) = path.split_zero(&a.0);
+
+                let jump = nonzero.and_then(|mut path| {
+                    path.ip = target as usize;
+                    // back in a state already explored: the same futures, or a loop
+                    self.seen.insert(path.state_hash()).then_some(path)
+                });
+
+                zero.into_iter().chain(jump).collect()
+            }
+            Instruction::Bxc => {
+                path.b = path.b.xor(&path.c);
+                vec![path]
+            }
+            Instruction::Out(operand) => {
+                let Some(&expected) = self.program.get(path.outputs) else {
+                    return Vec::new();
+                };
+                let value = path.combo(&operand);
+
+                for bit in 0..3 {
+                    if !path.assume(value.0[bit], expected >> bit & 1 == 1) {
+                        return Vec::new();
+                    }
+                }
+
+                path.outputs += 1;
+                vec![path]
+            }
+        }
+    }
+
+    fn halt(&mut self, path: Path) {
+        if path.outputs != self.program.len() {
+            return;
+        }
+
+        if let Some(a) = path.system.minimum_with(&path.nonzero) {
+            if self.best.is_none_or(|best| a < best) {
+                self.best = Some(a);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use anyhow::Result;
+    use common::parse_file;
+
+    use super::*;
+    use crate::{assemble, Day17};
+
+    fn computer(program: &str) -> Computer {
+        Computer {
+            program: assemble(program).unwrap(),
+            ..Computer::default()
+        }
+    }
+
+    /// Checks `find_quine` against running the program, and against brute force for small A.
+    fn assert_quine(program: &str, expected: u64) {
+        let computer = computer(program);
+        let compiled = computer.compile();
+
+        assert_eq!(find_quine(&computer), Ok(expected));
+        assert_eq!(compiled.output(expected).as_deref(), Ok(computer.program()));
+
+        let smaller = compiled.batch(0..expected.min(1 << 20), 4, |a, output| {
+            if output == Ok(computer.program()) {
+                ControlFlow::Break(a)
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        assert_eq!(smaller, None);
+    }
+
+    #[test]
+    fn solves_the_example() -> Result<()> {
+        let computer = parse_file::<Day17>("example_2.txt")?;
+
+        assert_eq!(find_quine(&computer), Ok(117440));
+        Ok(())
+    }
+
+    #[test]
+    fn solves_the_usual_puzzle_shape() {
+        let program = "bst a\nbxl 1\ncdv b\nbxl 5\nbxc\nout b\nadv 3\njnz 0\n";
+
+        assert_quine(program, 164279024971453);
+    }
+
+    #[test]
+    fn solves_programs_the_suffix_search_cannot() {
+        // the first output depends on B and C before A has been shifted at all
+        assert_quine(
+            "bxl 1\nout b\ncdv 1\nbxc\ncdv 1\nadv 3\njnz 0\n",
+            1396127362258,
+        );
+        // shifts A by a register, which is 0 on the first pass
+        assert_quine("cdv b\nbst 3\nout c\nadv b\njnz 0\n", 488289607);
+    }
+
+    #[test]
+    fn reports_programs_with_no_quine() {
+        // prints 0 until A runs out, but the program starts with 5
+        let halving = computer("out 0\nadv 1\njnz 0\n");
+        assert_eq!(find_quine(&halving), Err(QuineError::NoSolution));
+
+        // loops forever unless A is zero, and then prints nothing
+        let looping = computer("jnz 0\nout a\n");
+        assert_eq!(find_quine(&looping), Err(QuineError::NoSolution));
+    }
+
+    #[test]
+    fn reports_searches_that_run_out_of_budget() {
+        let mut computer = computer("bst a\nbxl 1\ncdv b\nbxl 5\nbxc\nout b\nadv 3\njnz 0\n");
+        computer.set_budget(100);
+
+        assert!(matches!(
+            find_quine(&computer),
+            Err(QuineError::Incomplete(ExecError::StepLimitExceeded { .. }))
+        ));
+    }
+}
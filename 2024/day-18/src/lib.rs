@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use common::{Params, Parser, Solution};
+use grid::{Grid, Pos};
+
+mod timeline;
+
+pub use timeline::{Connectivity, Timeline};
+
+const SIZE: usize = 71;
+const TIME: usize = 1024;
+
+pub struct Input {
+    bytes: Vec<Position>,
+    size: usize,
+    time: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    x: usize,
+    y: usize,
+}
+
+impl Position {
+    fn pos(self) -> Pos {
+        Pos::new(self.y, self.x)
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Input;
+
+    const PARAMS: &'static [&'static str] = &["size", "time"];
+
+    fn parse(input: &str) -> Result<Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input> {
+        let size = params.get("size", SIZE)?;
+        if size == 0 {
+            bail!("The memory space needs a size of at least 1");
+        }
+
+        let mut parser = Parser::new(input);
+        let bytes = parser.lines(|line| {
+            let x = coordinate(line, size)?;
+            line.literal(",")?;
+
+            Ok(Position {
+                x,
+                y: coordinate(line, size)?,
+            })
+        })?;
+        parser.end()?;
+
+        Ok(Input {
+            bytes,
+            size,
+            time: params.get("time", TIME)?,
+        })
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        shortest_path(input, input.time).context("Exit is unreachable")
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        let time = Timeline::new(input)
+            .first_unreachable()
+            .context("Exit is never cut off")?;
+
+        let byte = input.bytes[time - 1];
+        Ok(format!("{},{}", byte.x, byte.y))
+    }
+}
+
+fn coordinate(parser: &mut Parser, size: usize) -> Result<usize> {
+    let start = parser.clone();
+    let value = parser.number()?;
+
+    if value >= size {
+        return Err(start.error(format!(
+            "{} is outside the {}x{} memory space",
+            value, size, size
+        )));
+    }
+
+    Ok(value)
+}
+
+fn shortest_path(input: &Input, time: usize) -> Option<usize> {
+    let mut corrupt = Grid::new(input.size, input.size, false);
+    input.bytes.iter().take(time).for_each(|p| {
+        corrupt[p.pos()] = true;
+    });
+
+    let start = Pos::new(0, 0);
+    let end = Pos::new(input.size - 1, input.size - 1);
+
+    search::bfs(
+        start,
+        |&pos| corrupt.neighbors(pos).filter(|&next| !corrupt[next]),
+        |&pos| pos == end,
+    )
+    .map(|path| path.cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_bytes_outside_the_memory_space() {
+        let parse = |params: &str| Day18::parse_with("4,1\n0,5\n", &params.parse().unwrap());
+
+        assert!(parse("size=6").is_ok());
+        assert_eq!(
+            parse("size=5").err().unwrap().to_string(),
+            "5 is outside the 5x5 memory space at line 2, column 3"
+        );
+        assert!(parse("size=0").is_err());
+    }
+}
@@ -0,0 +1,182 @@
+use grid::{Grid, Pos};
+
+use crate::{Input, Position};
+
+/// How the open cells hang together after some number of bytes have fallen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Connectivity {
+    /// Separate regions of open cells.
+    pub regions: usize,
+    /// Whether the exit can be reached from the start.
+    pub reachable: bool,
+}
+
+/// When every cell is blocked and how connectivity changes as bytes fall,
+/// built in one pass by un-dropping the bytes in reverse into a union-find.
+pub struct Timeline {
+    blocked: Grid<Option<usize>>,
+    /// Indexed by time, from no bytes fallen to all of them.
+    connectivity: Vec<Connectivity>,
+}
+
+impl Timeline {
+    pub fn new(input: &Input) -> Self {
+        let size = input.size;
+        let mut blocked = Grid::new(size, size, None);
+
+        // a byte landing on an already corrupt cell changes nothing
+        for (index, byte) in input.bytes.iter().enumerate() {
+            blocked[byte.pos()].get_or_insert(index + 1);
+        }
+
+        let start = Pos::new(0, 0);
+        let end = Pos::new(size - 1, size - 1);
+        let mut sets = DisjointSet::new(size * size);
+        let mut open = Grid::new(size, size, false);
+        let mut regions = 0;
+
+        let mut reopen = |pos: Pos, sets: &mut DisjointSet, regions: &mut usize| {
+            open[pos] = true;
+            *regions += 1;
+
+            for next in open.neighbors(pos).collect::<Vec<_>>() {
+                if open[next] && sets.union(index(size, pos), index(size, next)) {
+                    *regions -= 1;
+                }
+            }
+        };
+
+        for pos in blocked.positions() {
+            if blocked[pos].is_none() {
+                reopen(pos, &mut sets, &mut regions);
+            }
+        }
+
+        let mut connectivity = Vec::with_capacity(input.bytes.len() + 1);
+
+        for time in (0..=input.bytes.len()).rev() {
+            let reachable = blocked[start].is_none_or(|blocked| blocked > time)
+                && blocked[end].is_none_or(|blocked| blocked > time)
+                && sets.find(index(size, start)) == sets.find(index(size, end));
+            connectivity.push(Connectivity { regions, reachable });
+
+            // step back to before this byte fell
+            if let Some(byte) = time.checked_sub(1).map(|index| input.bytes[index].pos()) {
+                if blocked[byte] == Some(time) {
+                    reopen(byte, &mut sets, &mut regions);
+                }
+            }
+        }
+        connectivity.reverse();
+
+        Self {
+            blocked,
+            connectivity,
+        }
+    }
+
+    /// How many bytes have fallen once `position` is corrupt, or `None` if it never is.
+    pub fn blocked_at(&self, position: Position) -> Option<usize> {
+        self.blocked[position.pos()]
+    }
+
+    /// Connectivity once `time` bytes have fallen.
+    pub fn connectivity(&self, time: usize) -> Connectivity {
+        self.connectivity[time.min(self.connectivity.len() - 1)]
+    }
+
+    /// Every time connectivity differs from the time before, starting with no bytes.
+    pub fn changes(&self) -> impl Iterator<Item = (usize, Connectivity)> + '_ {
+        self.connectivity
+            .iter()
+            .enumerate()
+            .filter(|&(time, connectivity)| {
+                time == 0 || self.connectivity[time - 1] != *connectivity
+            })
+            .map(|(time, &connectivity)| (time, connectivity))
+    }
+
+    /// How many bytes have fallen when the exit is first cut off.
+    pub fn first_unreachable(&self) -> Option<usize> {
+        self.connectivity
+            .iter()
+            .position(|connectivity| !connectivity.reachable)
+    }
+}
+
+fn index(size: usize, pos: Pos) -> usize {
+    pos.row * size + pos.col
+}
+
+struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, mut element: usize) -> usize {
+        while self.parent[element] != element {
+            self.parent[element] = self.parent[self.parent[element]];
+            element = self.parent[element];
+        }
+
+        element
+    }
+
+    /// Merges the two sets, returning whether they were separate.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.size[a] < self.size[b] {
+            (a, b) = (b, a);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use common::parse_file;
+
+    use super::*;
+    use crate::Day18;
+
+    #[test]
+    fn follows_the_example() -> Result<()> {
+        let input = parse_file::<Day18>("example.txt")?;
+        let timeline = Timeline::new(&input);
+
+        assert_eq!(timeline.blocked_at(input.bytes[0]), Some(1));
+        assert_eq!(timeline.blocked_at(Position { x: 0, y: 0 }), None);
+        assert_eq!(
+            timeline.connectivity(0),
+            Connectivity {
+                regions: 1,
+                reachable: true
+            }
+        );
+
+        let cut = timeline.first_unreachable().unwrap();
+        assert_eq!(input.bytes[cut - 1], Position { x: 6, y: 1 });
+        assert!(timeline.connectivity(cut - 1).reachable);
+
+        let changes: Vec<_> = timeline.changes().collect();
+        assert_eq!(changes[0].0, 0);
+        assert!(changes.contains(&(cut, timeline.connectivity(cut))));
+        assert!(changes.windows(2).all(|pair| pair[0].1 != pair[1].1));
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::{Parser, Solution};
+
+pub struct Input {
+    towels: Vec<Towel>,
+    patterns: Vec<Pattern>,
+}
+
+type Towel = Vec<Color>;
+type Pattern = Vec<Color>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    White,
+    Blue,
+    Black,
+    Red,
+    Green,
+}
+
+type Cache = HashMap<String, usize>;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        let mut parser = Parser::new(input);
+
+        let mut section = parser.expect_section("towels")?;
+        let towels = section.separated(", ", parse_pattern)?;
+        section.end()?;
+
+        let patterns = parser.expect_section("patterns")?.lines(parse_pattern)?;
+        parser.end()?;
+
+        Ok(Input { towels, patterns })
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(input
+            .patterns
+            .iter()
+            .filter(|pattern| can_make_pattern(&input.towels, pattern))
+            .count())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        let mut cache = Cache::new();
+
+        Ok(input
+            .patterns
+            .iter()
+            .filter(|pattern| can_make_pattern(&input.towels, pattern))
+            .map(|pattern| count_the_ways(&mut cache, input, pattern))
+            .sum::<usize>())
+    }
+}
+
+fn parse_pattern(parser: &mut Parser) -> Result<Pattern> {
+    let mut pattern = Vec::new();
+
+    while let Some(color) = parser.peek().and_then(parse_color) {
+        parser.consume();
+        pattern.push(color);
+    }
+
+    if pattern.is_empty() {
+        return Err(parser.error("Expected a pattern of colors"));
+    }
+
+    Ok(pattern)
+}
+
+fn format_pattern(pattern: &[Color]) -> String {
+    pattern.iter().map(format_color).collect()
+}
+
+fn parse_color(c: char) -> Option<Color> {
+    match c {
+        'w' => Some(Color::White),
+        'u' => Some(Color::Blue),
+        'b' => Some(Color::Black),
+        'r' => Some(Color::Red),
+        'g' => Some(Color::Green),
+        _ => None,
+    }
+}
+
+fn format_color(color: &Color) -> char {
+    match color {
+        Color::White => 'w',
+        Color::Blue => 'u',
+        Color::Black => 'b',
+        Color::Red => 'r',
+        Color::Green => 'g',
+    }
+}
+
+fn can_make_pattern(towels: &Vec<Towel>, pattern: &[Color]) -> bool {
+    if pattern.is_empty() {
+        return true;
+    }
+
+    for towel in towels {
+        if towel_matches_pattern(towel, pattern)
+            && can_make_pattern(towels, &pattern[towel.len()..])
+        {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn towel_matches_pattern(towel: &Towel, pattern: &[Color]) -> bool {
+    towel.len() <= pattern.len()
+        && towel
+            .iter()
+            .zip(pattern)
+            .all(|(towel_color, pattern_color)| towel_color == pattern_color)
+}
+
+fn count_the_ways(cache: &mut Cache, input: &Input, pattern: &[Color]) -> usize {
+    let mut ways = 0;
+
+    if pattern.is_empty() {
+        return 1;
+    }
+
+    let key = format_pattern(pattern);
+    if let Some(&cached_ways) = cache.get(&key) {
+        return cached_ways;
+    }
+
+    for towel in &input.towels {
+        if towel_matches_pattern(towel, pattern) {
+            ways += count_the_ways(cache, input, &pattern[towel.len()..]);
+        }
+    }
+
+    cache.insert(key, ways);
+    ways
+}
@@ -0,0 +1,57 @@
+//! Prints how many cheats save each amount of time, in the puzzle's own words,
+//! to compare against the tables in the puzzle text.
+
+use std::env;
+
+use anyhow::{bail, Context, Result};
+use common::InputArgs;
+use day_20::{histogram, Day20};
+
+const USAGE: &str =
+    "Usage: cheats [--input <path>] [--length <picoseconds>] [--min <picoseconds>] \
+    [--positions] [--param <key>=<value>]...";
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+    let mut input = InputArgs::new(2024, 20);
+    let mut length = 2;
+    let mut min = 1;
+    let mut positions = false;
+
+    while let Some(arg) = args.next() {
+        if input.take(&arg, &mut args)? {
+            continue;
+        }
+        let mut value = || args.next().context(USAGE);
+
+        match arg.as_str() {
+            "--length" => length = value()?.parse()?,
+            "--min" => min = value()?.parse()?,
+            "--positions" => positions = true,
+            _ => bail!("Unknown argument: {}\n{}", arg, USAGE),
+        }
+    }
+
+    let input = input.parse::<Day20>()?;
+
+    for (saved, cheats) in histogram(&input, length).range(min..) {
+        match cheats.len() {
+            1 => println!("There is one cheat that saves {} picoseconds.", saved),
+            count => println!(
+                "There are {} cheats that save {} picoseconds.",
+                count, saved
+            ),
+        }
+
+        if positions {
+            for cheat in cheats {
+                println!(
+                    "  {},{} -> {},{}",
+                    cheat.start.col, cheat.start.row, cheat.end.col, cheat.end.row
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,227 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use common::{Params, Solution};
+use grid::{Grid, Pos};
+
+const CHEAT_THRESHOLD: usize = 100;
+
+pub struct Input {
+    /// Picoseconds from the start to each track tile, `None` for walls and
+    /// track that can't be reached.
+    from_start: Grid<Option<usize>>,
+    /// Picoseconds from each track tile to the end.
+    from_end: Grid<Option<usize>>,
+    /// The fastest honest race.
+    best: usize,
+    threshold: usize,
+}
+
+/// Going through walls from `start` to `end`, both on the track.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cheat {
+    pub start: Pos,
+    pub end: Pos,
+    pub saved: usize,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Input;
+
+    const PARAMS: &'static [&'static str] = &["threshold"];
+
+    fn parse(input: &str) -> Result<Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Input> {
+        let mut start = None;
+        let mut end = None;
+        let track = Grid::parse_with(input, |pos, c| match c {
+            '#' => Ok(false),
+            '.' => Ok(true),
+            'S' => {
+                start = Some(pos);
+                Ok(true)
+            }
+            'E' => {
+                end = Some(pos);
+                Ok(true)
+            }
+            _ => bail!("Unknown object"),
+        })?;
+        let start = start.context("No start in map")?;
+        let end = end.context("No end in map")?;
+
+        let from_start = distances(&track, start);
+        let from_end = distances(&track, end);
+
+        Ok(Input {
+            best: from_start[end].context("The end can't be reached from the start")?,
+            from_start,
+            from_end,
+            threshold: params.get("threshold", CHEAT_THRESHOLD)?,
+        })
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(cheats(input, 2)
+            .filter(|cheat| cheat.saved >= input.threshold)
+            .count())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Ok(cheats(input, 20)
+            .filter(|cheat| cheat.saved >= input.threshold)
+            .count())
+    }
+}
+
+/// Breadth-first distances along the track, so branches and loops are fine.
+fn distances(track: &Grid<bool>, from: Pos) -> Grid<Option<usize>> {
+    let mut distances = Grid::new(track.width(), track.height(), None);
+    let mut queue = VecDeque::from([from]);
+    distances[from] = Some(0);
+
+    while let Some(pos) = queue.pop_front() {
+        let distance = distances[pos].unwrap() + 1;
+
+        for next in track.neighbors(pos) {
+            if track[next] && distances[next].is_none() {
+                distances[next] = Some(distance);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Every cheat of at most `length` picoseconds that beats the fastest honest race.
+pub fn cheats(input: &Input, length: usize) -> impl Iterator<Item = Cheat> + '_ {
+    let reach = length as isize;
+
+    input
+        .from_start
+        .iter()
+        .filter_map(|(pos, distance)| Some((pos, (*distance)?)))
+        .flat_map(move |(start, before)| {
+            (-reach..=reach).flat_map(move |rows| {
+                let cols = reach - rows.abs();
+
+                (-cols..=cols).filter_map(move |cols| {
+                    let end = Pos {
+                        row: start.row.checked_add_signed(rows)?,
+                        col: start.col.checked_add_signed(cols)?,
+                    };
+                    let after = (*input.from_end.get(end)?)?;
+                    let time = before + start.manhattan(end) + after;
+
+                    (time < input.best).then(|| Cheat {
+                        start,
+                        end,
+                        saved: input.best - time,
+                    })
+                })
+            })
+        })
+}
+
+/// Every cheat of at most `length` picoseconds, grouped by the time it saves.
+pub fn histogram(input: &Input, length: usize) -> BTreeMap<usize, Vec<Cheat>> {
+    let mut histogram: BTreeMap<usize, Vec<Cheat>> = BTreeMap::new();
+
+    for cheat in cheats(input, length) {
+        histogram.entry(cheat.saved).or_default().push(cheat);
+    }
+
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse_file;
+
+    use super::*;
+
+    fn counts(histogram: &BTreeMap<usize, Vec<Cheat>>, min: usize) -> Vec<(usize, usize)> {
+        histogram
+            .range(min..)
+            .map(|(&saved, cheats)| (cheats.len(), saved))
+            .collect()
+    }
+
+    #[test]
+    fn matches_the_published_tables() -> Result<()> {
+        let input = parse_file::<Day20>("example.txt")?;
+
+        assert_eq!(
+            counts(&histogram(&input, 2), 0),
+            [
+                (14, 2),
+                (14, 4),
+                (2, 6),
+                (4, 8),
+                (2, 10),
+                (3, 12),
+                (1, 20),
+                (1, 36),
+                (1, 38),
+                (1, 40),
+                (1, 64)
+            ]
+        );
+        assert_eq!(
+            counts(&histogram(&input, 20), 50),
+            [
+                (32, 50),
+                (31, 52),
+                (29, 54),
+                (39, 56),
+                (25, 58),
+                (23, 60),
+                (20, 62),
+                (19, 64),
+                (12, 66),
+                (14, 68),
+                (12, 70),
+                (22, 72),
+                (4, 74),
+                (3, 76)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn handles_branching_mazes() -> Result<()> {
+        let input = Day20::parse("#######\n#.....#\n#.#.#.#\n#S#E#.#\n#######\n")?;
+
+        assert_eq!(input.best, 6);
+        assert_eq!(
+            histogram(&input, 2),
+            BTreeMap::from([
+                (
+                    2,
+                    vec![Cheat {
+                        start: Pos::new(2, 1),
+                        end: Pos::new(2, 3),
+                        saved: 2
+                    }]
+                ),
+                (
+                    4,
+                    vec![Cheat {
+                        start: Pos::new(3, 1),
+                        end: Pos::new(3, 3),
+                        saved: 4
+                    }]
+                ),
+            ])
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::BinaryHeap;
+use std::fmt::{self, Display};
+
+use anyhow::{bail, Result};
+use bitvec::{bitarr, BitArr};
+use common::{Parser, Solution};
+
+const A: u8 = b'a';
+const LETTER_RADIX: usize = 26;
+const MAX_NODES: usize = LETTER_RADIX * LETTER_RADIX;
+type NodeSet = BitArr![for MAX_NODES];
+pub type Input = [NodeSet; MAX_NODES];
+
+struct Partition {
+    nodes: NodeSet,
+    prefix: usize,
+    size: usize,
+}
+
+impl Partition {
+    fn all_connected(&self, input: &Input) -> bool {
+        self.nodes
+            .iter_ones()
+            .fold(bitarr![1; MAX_NODES], |acc, index| acc & input[index])
+            == self.nodes
+    }
+}
+
+impl Display for Partition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names = self
+            .nodes
+            .iter_ones()
+            .map(index_to_chars)
+            .map(|(a, b)| format!("{}{}", a, b))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", names.join(","))
+    }
+}
+
+impl PartialEq for Partition {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+    }
+}
+
+impl Eq for Partition {}
+
+impl PartialOrd for Partition {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Partition {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.size.cmp(&other.size)
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        let mut graph = [bitarr![0; MAX_NODES]; MAX_NODES];
+
+        Parser::new(input).lines(|line| {
+            let first = parse_computer(line)?;
+            line.literal("-")?;
+            let second = parse_computer(line)?;
+
+            graph[first].set(first, true);
+            graph[first].set(second, true);
+            graph[second].set(first, true);
+            graph[second].set(second, true);
+            Ok(())
+        })?;
+
+        Ok(graph)
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        let t_range = chars_to_index('t', 'a')..chars_to_index('u', 'a');
+
+        Ok(t_range
+            .clone()
+            .map(|first| {
+                input[first]
+                    .iter_ones()
+                    .filter(|&second| !t_range.contains(&second) || second < first)
+                    .map(|second| {
+                        (input[first] & input[second])
+                            .iter_ones()
+                            .take_while(|&third| third < second)
+                            .filter(|&third| !t_range.contains(&third) || third < first)
+                            .count()
+                    })
+                    .sum::<usize>()
+            })
+            .sum::<usize>())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        let first = {
+            let mut all = bitarr![0; MAX_NODES];
+            for (index, adj) in input.iter().enumerate() {
+                all.set(index, adj.any());
+            }
+            Partition {
+                nodes: all,
+                prefix: 0,
+                size: all.count_ones() as usize,
+            }
+        };
+
+        let mut queue = BinaryHeap::new();
+        queue.push(first);
+
+        while let Some(partition) = queue.pop() {
+            if partition.all_connected(input) {
+                return Ok(partition.to_string());
+            }
+
+            let partition_on = {
+                let (_, suffix) = partition.nodes.split_at(partition.prefix);
+                suffix.first_one().unwrap() + partition.prefix
+            };
+
+            let mut connected = Partition {
+                nodes: bitarr![0; MAX_NODES],
+                prefix: partition_on + 1,
+                size: 0,
+            };
+            let mut unconnected = Partition {
+                nodes: bitarr![0; MAX_NODES],
+                prefix: partition_on + 1,
+                size: 0,
+            };
+
+            for index in partition.nodes.iter_ones() {
+                if index == partition_on || *input[index].get(partition_on).unwrap() {
+                    connected.nodes.set(index, true);
+                    connected.size += 1;
+                } else {
+                    unconnected.nodes.set(index, true);
+                    unconnected.size += 1;
+                }
+            }
+
+            if connected.size > 0 {
+                queue.push(connected);
+            }
+
+            if unconnected.size > 0 {
+                queue.push(unconnected);
+            }
+        }
+
+        bail!("Failed to find any partition")
+    }
+}
+
+fn parse_computer(parser: &mut Parser) -> Result<usize> {
+    let start = parser.clone();
+
+    match (parser.consume(), parser.consume()) {
+        (Some(a @ 'a'..='z'), Some(b @ 'a'..='z')) => Ok(chars_to_index(a, b)),
+        _ => Err(start.error("Expected a two letter name")),
+    }
+}
+
+fn chars_to_index(a: char, b: char) -> usize {
+    let a = (a as u8 - A) as usize;
+    let b = (b as u8 - A) as usize;
+
+    a * LETTER_RADIX + b
+}
+
+fn index_to_chars(index: usize) -> (char, char) {
+    let a = (index / LETTER_RADIX) as u8 + A;
+    let b = (index % LETTER_RADIX) as u8 + A;
+
+    (a as char, b as char)
+}
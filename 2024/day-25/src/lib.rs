@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use common::Solution;
+
+pub type Input = Vec<Vec<char>>;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input> {
+        Ok(input
+            .lines()
+            .map(|l| l.chars().collect::<Vec<char>>())
+            .collect::<Input>())
+    }
+
+    fn part_one(input: &Input) -> Result<impl Display> {
+        Ok(input.len())
+    }
+
+    fn part_two(input: &Input) -> Result<impl Display> {
+        Ok(input.len())
+    }
+}
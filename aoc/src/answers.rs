@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use common::Parser;
+
+use crate::Part;
+
+/// The recorded answers for one input file of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answers {
+    pub input: String,
+    pub expected: Vec<(Part, String)>,
+}
+
+/// Reads an `answers.txt` file. Each line names an input file next to it and
+/// the answers for both parts, with `-` for a part that shouldn't be checked:
+///
+/// ```text
+/// # input       part 1   part 2
+/// example.txt   11       31
+/// ```
+///
+/// `input.txt` stands for the puzzle input, wherever the `InputStore` keeps it.
+/// A missing file means nothing has been recorded yet.
+pub fn read(filename: &Path) -> Result<Vec<Answers>> {
+    if !filename.exists() {
+        return Ok(vec![]);
+    }
+
+    let contents = fs::read_to_string(filename)
+        .with_context(|| format!("Failed to read {}", filename.display()))?;
+
+    parse(&contents).with_context(|| format!("Failed to parse {}", filename.display()))
+}
+
+pub fn parse(contents: &str) -> Result<Vec<Answers>> {
+    let answers = Parser::new(contents).lines(|line| {
+        let start = line.clone();
+        let line = line.line().split('#').next().unwrap().trim();
+        if line.is_empty() {
+            return Ok(None);
+        }
+
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let [input, one, two] = fields[..] else {
+            return Err(start.error(format!(
+                "Expected <input> <part 1> <part 2>, found {:?}",
+                line
+            )));
+        };
+
+        let expected = [(Part::One, one), (Part::Two, two)]
+            .into_iter()
+            .filter(|&(_, answer)| answer != "-")
+            .map(|(part, answer)| (part, answer.to_string()))
+            .collect();
+
+        Ok(Some(Answers {
+            input: input.to_string(),
+            expected,
+        }))
+    })?;
+
+    Ok(answers.into_iter().flatten().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_comments_and_unchecked_parts() {
+        let answers =
+            parse("# input part-1 part-2\n\nexample.txt 7 -\ninput.txt 1 co,de\n").unwrap();
+
+        assert_eq!(
+            answers,
+            [
+                Answers {
+                    input: "example.txt".to_string(),
+                    expected: vec![(Part::One, "7".to_string())],
+                },
+                Answers {
+                    input: "input.txt".to_string(),
+                    expected: vec![
+                        (Part::One, "1".to_string()),
+                        (Part::Two, "co,de".to_string())
+                    ],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_missing_parts() {
+        let error = parse("example.txt 7\n").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Expected <input> <part 1> <part 2>, found \"example.txt 7\" at line 1, column 1"
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use common::{Params, Solution};
+use serde::{Deserialize, Serialize};
+
+/// How many untimed warm-up runs precede the timed samples of each step.
+#[derive(Clone, Copy, Debug)]
+pub struct Options {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            warmup: 2,
+            samples: 10,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+pub(crate) fn bench<S: Solution>(
+    filename: &str,
+    params: &Params,
+    options: &Options,
+) -> Result<Timings> {
+    let (contents, params) = common::read_input::<S>(filename, params)?;
+    let input = S::parse_with(&contents, &params)?;
+
+    // don't time a part that can't answer
+    S::part_one(&input)?;
+    S::part_two(&input)?;
+
+    Ok(Timings {
+        parse: sample(options, || S::parse_with(black_box(&contents), &params)),
+        part_one: sample(options, || S::part_one(black_box(&input))),
+        part_two: sample(options, || S::part_two(black_box(&input))),
+    })
+}
+
+fn sample<T>(options: &Options, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    let mut samples = (0..options.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    samples.sort();
+
+    Stats {
+        median: samples[samples.len() / 2],
+        min: samples[0],
+    }
+}
+
+/// Median nanoseconds per step, keyed by day, saved between runs to spot regressions.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline(BTreeMap<String, Entry>);
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Entry {
+    pub parse_ns: u64,
+    pub part_one_ns: u64,
+    pub part_two_ns: u64,
+}
+
+impl Baseline {
+    pub fn load(filename: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(filename)
+            .with_context(|| format!("Failed to read {}", filename.display()))?;
+
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse {}", filename.display()))
+    }
+
+    pub fn save(&self, filename: &Path) -> Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+
+        fs::write(filename, contents + "\n")
+            .with_context(|| format!("Failed to write {}", filename.display()))
+    }
+
+    pub fn get(&self, day: &str) -> Option<&Entry> {
+        self.0.get(day)
+    }
+
+    pub fn insert(&mut self, day: String, timings: &Timings) {
+        let nanos = |stats: Stats| stats.median.as_nanos() as u64;
+
+        self.0.insert(
+            day,
+            Entry {
+                parse_ns: nanos(timings.parse),
+                part_one_ns: nanos(timings.part_one),
+                part_two_ns: nanos(timings.part_two),
+            },
+        );
+    }
+}
+
+/// One table row: the median of each step, with the change against `baseline` if given.
+pub fn row(day: &str, timings: &Timings, baseline: Option<&Entry>) -> String {
+    let steps = [
+        (timings.parse, baseline.map(|b| b.parse_ns)),
+        (timings.part_one, baseline.map(|b| b.part_one_ns)),
+        (timings.part_two, baseline.map(|b| b.part_two_ns)),
+    ];
+
+    let cells = steps
+        .iter()
+        .map(|&(stats, before)| {
+            let cell = format!("{:.1?}", stats.median);
+
+            match before {
+                Some(before) if before > 0 => {
+                    let change = stats.median.as_nanos() as f64 / before as f64 - 1.0;
+                    format!("{:>22}", format!("{} ({:+.0}%)", cell, change * 100.0))
+                }
+                _ => format!("{:>22}", cell),
+            }
+        })
+        .collect::<String>();
+
+    format!("{:<12}{}", day, cells)
+}
+
+pub fn header() -> String {
+    format!(
+        "{:<12}{:>22}{:>22}{:>22}",
+        "day", "parse", "part 1", "part 2"
+    )
+}
@@ -0,0 +1,7 @@
+pub mod answers;
+pub mod bench;
+mod part;
+mod registry;
+
+pub use part::Part;
+pub use registry::{find, Day, DAYS};
@@ -0,0 +1,108 @@
+use std::env;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use aoc::bench::{self, Baseline, Options};
+use aoc::{find, Part, DAYS};
+use common::{InputArgs, Params};
+
+const USAGE: &str = "Usage: aoc run <year> <day> [--part <1|2>] [--input <path>] [--param <key>=<value>]...
+       aoc bench [<year> [<day>]] [--warmup <n>] [--samples <n>] [--save <path>] [--compare <path>]";
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+
+    match args.next().as_deref() {
+        Some("run") => run(args),
+        Some("bench") => bench(args),
+        _ => bail!(USAGE),
+    }
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let year = args.next().context(USAGE)?.parse()?;
+    let day = args.next().context(USAGE)?.parse()?;
+    let mut parts = vec![Part::One, Part::Two];
+    let mut inputs = InputArgs::new(year, day);
+
+    while let Some(arg) = args.next() {
+        if inputs.take(&arg, &mut args)? {
+            continue;
+        }
+
+        match arg.as_str() {
+            "--part" => parts = vec![args.next().context(USAGE)?.parse()?],
+            _ => bail!("Unknown argument: {}\n{}", arg, USAGE),
+        }
+    }
+
+    let day = find(year, day)?;
+    let input = inputs.input()?;
+    let answers = day.solve(&input, &inputs.params, &parts).with_context(|| {
+        format!(
+            "Failed to solve {} day {} using {}",
+            day.year, day.day, input
+        )
+    })?;
+
+    for (part, answer) in parts.iter().zip(answers) {
+        println!("Part {}: {}", part, answer);
+    }
+
+    Ok(())
+}
+
+fn bench(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut year = None;
+    let mut day = None;
+    let mut options = Options::default();
+    let mut save = None;
+    let mut compare = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--warmup" => options.warmup = args.next().context(USAGE)?.parse()?,
+            "--samples" => options.samples = args.next().context(USAGE)?.parse()?,
+            "--save" => save = Some(PathBuf::from(args.next().context(USAGE)?)),
+            "--compare" => compare = Some(PathBuf::from(args.next().context(USAGE)?)),
+            _ if arg.starts_with("--") => bail!("Unknown argument: {}\n{}", arg, USAGE),
+            _ if year.is_none() => year = Some(arg.parse::<u16>()?),
+            _ if day.is_none() => day = Some(arg.parse::<u8>()?),
+            _ => bail!("Unexpected argument: {}\n{}", arg, USAGE),
+        }
+    }
+
+    let baseline = compare.as_deref().map(Baseline::load).transpose()?;
+    let mut results = Baseline::default();
+
+    println!("{}", bench::header());
+
+    for day in DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|year| d.year == year))
+        .filter(|d| day.is_none_or(|day| d.day == day))
+    {
+        let label = day.to_string();
+        let input = day.input_path();
+
+        if !input.exists() {
+            println!("{:<12}{:>22}", label, "no input");
+            continue;
+        }
+
+        let input = input.to_string_lossy();
+        let timings = day
+            .bench(&input, &Params::default(), &options)
+            .with_context(|| format!("Failed to benchmark {} using {}", day, input))?;
+        let before = baseline.as_ref().and_then(|b| b.get(&label));
+
+        println!("{}", bench::row(&label, &timings, before));
+        results.insert(label, &timings);
+    }
+
+    if let Some(save) = save {
+        results.save(&save)?;
+    }
+
+    Ok(())
+}
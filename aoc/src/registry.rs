@@ -0,0 +1,124 @@
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use common::{InputStore, Params, Solution};
+
+use crate::answers::{self, Answers};
+use crate::bench::{bench, Options, Timings};
+use crate::Part;
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    solve: fn(&str, &Params, &[Part]) -> Result<Vec<String>>,
+    bench: fn(&str, &Params, &Options) -> Result<Timings>,
+}
+
+impl Day {
+    /// Parses `filename` once and returns the answer for each of `parts`, in order.
+    /// `params` override the defaults declared next to `filename`.
+    pub fn solve(&self, filename: &str, params: &Params, parts: &[Part]) -> Result<Vec<String>> {
+        (self.solve)(filename, params, parts)
+    }
+
+    /// Times parsing and each part separately on `filename`.
+    pub fn bench(&self, filename: &str, params: &Params, options: &Options) -> Result<Timings> {
+        (self.bench)(filename, params, options)
+    }
+
+    /// The day's crate directory, e.g. `2024/day-16`.
+    pub fn dir(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("aoc crate lives inside the workspace")
+            .join(self.year.to_string())
+            .join(format!("day-{:02}", self.day))
+    }
+
+    /// Where the `InputStore` keeps the day's puzzle input, whether or not it's there yet.
+    pub fn input_path(&self) -> PathBuf {
+        InputStore::from_env().path(self.year, self.day)
+    }
+
+    /// The answers recorded in the day's `answers.txt`.
+    pub fn answers(&self) -> Result<Vec<Answers>> {
+        answers::read(&self.dir().join("answers.txt"))
+    }
+}
+
+fn solve<S: Solution>(filename: &str, params: &Params, parts: &[Part]) -> Result<Vec<String>> {
+    let input = common::parse_file_with::<S>(filename, params)?;
+
+    parts
+        .iter()
+        .map(|part| {
+            Ok(match part {
+                Part::One => S::part_one(&input)?.to_string(),
+                Part::Two => S::part_two(&input)?.to_string(),
+            })
+        })
+        .collect()
+}
+
+macro_rules! days {
+    ($($year:literal $day:literal => $solution:path,)*) => {
+        pub const DAYS: &[Day] = &[
+            $(Day {
+                year: $year,
+                day: $day,
+                solve: solve::<$solution>,
+                bench: bench::<$solution>,
+            },)*
+        ];
+    };
+}
+
+days! {
+    2023 1 => aoc_2023_day_01::Day01,
+    2023 2 => aoc_2023_day_02::Day02,
+    2023 3 => aoc_2023_day_03::Day03,
+    2023 4 => aoc_2023_day_04::Day04,
+    2023 5 => aoc_2023_day_05::Day05,
+    2023 6 => aoc_2023_day_06::Day06,
+    2023 7 => aoc_2023_day_07::Day07,
+    2023 8 => aoc_2023_day_08::Day08,
+    2023 9 => aoc_2023_day_09::Day09,
+    2024 1 => day_01::Day01,
+    2024 2 => day_02::Day02,
+    2024 3 => day_03::Day03,
+    2024 4 => day_04::Day04,
+    2024 5 => day_05::Day05,
+    2024 6 => day_06::Day06,
+    2024 7 => day_07::Day07,
+    2024 8 => day_08::Day08,
+    2024 9 => day_09::Day09,
+    2024 10 => day_10::Day10,
+    2024 11 => day_11::Day11,
+    2024 12 => day_12::Day12,
+    2024 13 => day_13::Day13,
+    2024 14 => day_14::Day14,
+    2024 15 => day_15::Day15,
+    2024 16 => day_16::Day16,
+    2024 17 => day_17::Day17,
+    2024 18 => day_18::Day18,
+    2024 19 => day_19::Day19,
+    2024 20 => day_20::Day20,
+    2024 21 => day_21::Day21,
+    2024 22 => day_22::Day22,
+    2024 23 => day_23::Day23,
+    2024 24 => day_24::Day24,
+    2024 25 => day_25::Day25,
+}
+
+impl Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} day {:02}", self.year, self.day)
+    }
+}
+
+pub fn find(year: u16, day: u8) -> Result<&'static Day> {
+    DAYS.iter()
+        .find(|d| d.year == year && d.day == day)
+        .ok_or_else(|| anyhow!("No solution registered for {} day {}", year, day))
+}
@@ -0,0 +1,76 @@
+//! Runs every registered day against the answers recorded in its `answers.txt`.
+//! Inputs that aren't on disk, like the uncommitted 2024 puzzle inputs, are ignored
+//! rather than downloaded.
+
+use std::path::PathBuf;
+
+use aoc::answers::Answers;
+use aoc::{Day, Part, DAYS};
+use common::Params;
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn main() {
+    let args = Arguments::from_args();
+    let trials = DAYS.iter().flat_map(trials).collect();
+
+    libtest_mimic::run(&args, trials).exit();
+}
+
+fn trials(day: &'static Day) -> Vec<Trial> {
+    let name = format!("{}::day_{:02}", day.year, day.day);
+
+    match day.answers() {
+        Ok(answers) => answers
+            .into_iter()
+            .map(|answers| {
+                let missing = !input_path(day, &answers).exists();
+
+                Trial::test(format!("{}::{}", name, answers.input), move || {
+                    check(day, &answers)
+                })
+                .with_ignored_flag(missing)
+            })
+            .collect(),
+        Err(error) => vec![Trial::test(format!("{}::answers", name), move || {
+            Err(format!("{:#}", error).into())
+        })],
+    }
+}
+
+/// Examples sit next to the day's crate, the puzzle input lives in the `InputStore`.
+fn input_path(day: &Day, answers: &Answers) -> PathBuf {
+    if answers.input == "input.txt" {
+        day.input_path()
+    } else {
+        day.dir().join(&answers.input)
+    }
+}
+
+fn check(day: &Day, answers: &Answers) -> Result<(), Failed> {
+    let input = input_path(day, answers);
+    let parts = answers
+        .expected
+        .iter()
+        .map(|&(part, _)| part)
+        .collect::<Vec<Part>>();
+
+    let actual = day
+        .solve(&input.to_string_lossy(), &Params::default(), &parts)
+        .map_err(|error| format!("{:#}", error))?;
+
+    let mismatches = answers
+        .expected
+        .iter()
+        .zip(actual)
+        .filter(|((_, expected), actual)| expected != actual)
+        .map(|((part, expected), actual)| {
+            format!("part {}: expected {}, got {}", part, expected, actual)
+        })
+        .collect::<Vec<_>>();
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(mismatches.join("\n").into())
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::{Context, Result};
+
+use crate::{parse_file_with, InputStore, Params, Solution};
+
+/// The `--input <path>` and `--param <key>=<value>` arguments every binary
+/// takes, and the puzzle input they pick out.
+#[derive(Clone, Debug)]
+pub struct InputArgs {
+    year: u16,
+    day: u8,
+    input: Option<String>,
+    pub params: Params,
+}
+
+impl InputArgs {
+    pub fn new(year: u16, day: u8) -> Self {
+        Self {
+            year,
+            day,
+            input: None,
+            params: Params::default(),
+        }
+    }
+
+    /// Handles `arg` if it's one of these, reading its value from `rest`.
+    /// Returns whether it was, so the caller can go on to its own arguments.
+    pub fn take(&mut self, arg: &str, rest: &mut impl Iterator<Item = String>) -> Result<bool> {
+        let mut value = || {
+            rest.next()
+                .with_context(|| format!("Expected a value after {}", arg))
+        };
+
+        match arg {
+            "--input" => self.input = Some(value()?),
+            "--param" => self.params.insert_pair(&value()?)?,
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    /// The path given with `--input`, or else the `InputStore`'s copy of the
+    /// day's input, downloading it if need be.
+    pub fn input(&self) -> Result<String> {
+        match &self.input {
+            Some(input) => Ok(input.clone()),
+            None => Ok(InputStore::from_env()
+                .get(self.year, self.day)?
+                .to_string_lossy()
+                .into_owned()),
+        }
+    }
+
+    /// Reads and parses the input with the given params.
+    pub fn parse<S: Solution>(&self) -> Result<S::Input> {
+        parse_file_with::<S>(&self.input()?, &self.params)
+    }
+}
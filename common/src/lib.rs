@@ -0,0 +1,86 @@
+use std::env;
+use std::fmt::Display;
+use std::fs;
+
+use anyhow::{bail, Context, Result};
+
+pub mod args;
+pub mod params;
+pub mod parser;
+pub mod store;
+
+pub use args::InputArgs;
+pub use params::Params;
+pub use parser::{Location, Parser};
+pub use store::InputStore;
+
+/// A single day's puzzle: how to parse its input and answer both parts.
+pub trait Solution {
+    type Input;
+
+    /// The names `parse_with` reads from its `Params`.
+    const PARAMS: &'static [&'static str] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Result<impl Display>;
+    fn part_two(input: &Self::Input) -> Result<impl Display>;
+
+    /// Parses with puzzle parameters that differ between the examples and the
+    /// real input. Days with `PARAMS` override this; `parse` uses the real defaults.
+    /// Those defaults are the day's constants, and each example's `.params` file
+    /// overrides them.
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let _ = params;
+        Self::parse(input)
+    }
+}
+
+/// Reads `filename` along with its params: the defaults declared next to it,
+/// overridden by `overrides`.
+pub fn read_input<S: Solution>(filename: &str, overrides: &Params) -> Result<(String, Params)> {
+    let contents =
+        fs::read_to_string(filename).with_context(|| format!("Failed to read {}", filename))?;
+
+    let mut params = Params::for_input(filename)?;
+    params.merge(overrides);
+    params.check(S::PARAMS)?;
+
+    Ok((contents, params))
+}
+
+pub fn parse_file<S: Solution>(filename: &str) -> Result<S::Input> {
+    parse_file_with::<S>(filename, &Params::default())
+}
+
+pub fn parse_file_with<S: Solution>(filename: &str, overrides: &Params) -> Result<S::Input> {
+    let (contents, params) = read_input::<S>(filename, overrides)?;
+
+    S::parse_with(&contents, &params)
+}
+
+/// Parses `filename` and prints both answers.
+pub fn run<S: Solution>(filename: &str, params: &Params) -> Result<()> {
+    let input = parse_file_with::<S>(filename, params)?;
+
+    println!("Part 1: {}", S::part_one(&input)?);
+    println!("Part 2: {}", S::part_two(&input)?);
+
+    Ok(())
+}
+
+const USAGE: &str = "Usage: [--input <path>] [--param <key>=<value>]...";
+
+/// The entry point of each day's binary, reading the puzzle input from the
+/// `InputStore` unless told otherwise.
+pub fn main<S: Solution>(year: u16, day: u8) -> Result<()> {
+    let mut args = env::args().skip(1);
+    let mut input = InputArgs::new(year, day);
+
+    while let Some(arg) = args.next() {
+        if !input.take(&arg, &mut args)? {
+            bail!("Unknown argument: {}\n{}", arg, USAGE);
+        }
+    }
+
+    run::<S>(&input.input()?, &input.params)
+}
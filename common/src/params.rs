@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context, Error, Result};
+
+use crate::Parser;
+
+/// Named puzzle parameters, such as a grid size that differs between the
+/// examples and the real input.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    /// The defaults declared next to `input`: `example.txt` reads `example.params`.
+    /// Inputs without one get no defaults.
+    pub fn for_input(input: &str) -> Result<Params> {
+        let filename = Path::new(input).with_extension("params");
+
+        if !filename.exists() {
+            return Ok(Params::default());
+        }
+
+        fs::read_to_string(&filename)
+            .with_context(|| format!("Failed to read {}", filename.display()))?
+            .parse()
+            .with_context(|| format!("Failed to parse {}", filename.display()))
+    }
+
+    /// Adds a `key=value` pair, as given to `--param`.
+    pub fn insert_pair(&mut self, pair: &str) -> Result<()> {
+        let (key, value) = pair
+            .split_once('=')
+            .with_context(|| format!("Expected key=value, found {:?}", pair))?;
+
+        self.0
+            .insert(key.trim().to_string(), value.trim().to_string());
+        Ok(())
+    }
+
+    /// Overrides these params with every value in `other`.
+    pub fn merge(&mut self, other: &Params) {
+        self.0
+            .extend(other.0.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+
+    /// Fails on any key outside `known`, so a typo doesn't silently fall back to a default.
+    pub fn check(&self, known: &[&str]) -> Result<()> {
+        match self.0.keys().find(|key| !known.contains(&key.as_str())) {
+            Some(key) if known.is_empty() => bail!("Unknown param {:?}, this day takes none", key),
+            Some(key) => bail!(
+                "Unknown param {:?}, expected one of {}",
+                key,
+                known.join(", ")
+            ),
+            None => Ok(()),
+        }
+    }
+
+    pub fn get<T>(&self, key: &str, default: T) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.0.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|e| anyhow!("Invalid value {:?} for param {}: {}", value, key, e)),
+            None => Ok(default),
+        }
+    }
+}
+
+/// One `key=value` pair per line; blank lines and `#` comments are skipped.
+impl FromStr for Params {
+    type Err = Error;
+
+    fn from_str(contents: &str) -> Result<Self> {
+        let mut params = Params::default();
+
+        Parser::new(contents).lines(|line| {
+            let start = line.clone();
+            let pair = line.line().split('#').next().unwrap().trim();
+
+            if !pair.is_empty() {
+                params
+                    .insert_pair(pair)
+                    .map_err(|error| start.error(error))?;
+            }
+
+            Ok(())
+        })?;
+
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_and_checks_keys() {
+        let mut params: Params = "# example grid\nwidth=11\nheight = 7\n".parse().unwrap();
+        let mut overrides = Params::default();
+        overrides.insert_pair("width=13").unwrap();
+        params.merge(&overrides);
+
+        assert_eq!(params.get("width", 101).unwrap(), 13);
+        assert_eq!(params.get("height", 103).unwrap(), 7);
+        assert_eq!(params.get("time", 100).unwrap(), 100);
+        assert!(params.check(&["width", "height"]).is_ok());
+
+        let error = params.check(&["width"]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unknown param \"height\", expected one of width"
+        );
+
+        let error = "width=11\nheight\n".parse::<Params>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Expected key=value, found \"height\" at line 2, column 1"
+        );
+    }
+}
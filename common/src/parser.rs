@@ -0,0 +1,346 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+
+/// A 0-based position in the puzzle text. Displays 1-based, as editors show it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line + 1, self.column + 1)
+    }
+}
+
+/// A cursor over puzzle text that knows its `Location`, so every error can say
+/// where parsing stopped.
+#[derive(Clone, Debug)]
+pub struct Parser<'a> {
+    rest: &'a str,
+    loc: Location,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            rest: input,
+            loc: Location::default(),
+        }
+    }
+
+    pub fn loc(&self) -> Location {
+        self.loc
+    }
+
+    /// The text not yet consumed.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    pub fn consume(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.rest = &self.rest[c.len_utf8()..];
+
+        if c == '\n' {
+            self.loc.line += 1;
+            self.loc.column = 0;
+        } else {
+            self.loc.column += 1;
+        }
+
+        Some(c)
+    }
+
+    /// An error pointing at the current location.
+    pub fn error(&self, message: impl Display) -> Error {
+        anyhow!("{} at {}", message, self.loc)
+    }
+
+    /// Consumes `expected` if the text starts with it.
+    pub fn eat(&mut self, expected: &str) -> bool {
+        if self.rest.starts_with(expected) {
+            self.take(expected.len());
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn literal(&mut self, expected: &str) -> Result<()> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(format!("Expected {:?}", expected)))
+        }
+    }
+
+    pub fn take_while(&mut self, mut predicate: impl FnMut(char) -> bool) -> &'a str {
+        let len = self.rest.find(|c| !predicate(c)).unwrap_or(self.rest.len());
+
+        self.take(len)
+    }
+
+    /// Skips spaces and tabs, but not newlines.
+    pub fn spaces(&mut self) {
+        self.take_while(|c| c == ' ' || c == '\t');
+    }
+
+    /// An unsigned number.
+    pub fn number<T>(&mut self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let start = self.clone();
+        let digits = self.take_while(|c| c.is_ascii_digit());
+
+        parse_token(&start, digits)
+    }
+
+    /// A number with an optional leading `+` or `-`.
+    pub fn signed<T>(&mut self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let start = self.clone();
+        let sign = if self.rest.starts_with(['+', '-']) {
+            1
+        } else {
+            0
+        };
+        let digits = self.rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len() - sign);
+        let token = self.take(sign + digits);
+
+        parse_token(&start, token)
+    }
+
+    /// A `label` followed by optional spaces and a signed number, as in `Register A: 729`.
+    pub fn field<T>(&mut self, label: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.literal(label)?;
+        self.spaces();
+        self.signed()
+    }
+
+    /// The rest of the current line, without its `\n` or `\r\n`.
+    pub fn line(&mut self) -> &'a str {
+        self.take_while(|c| c != '\r' && c != '\n')
+    }
+
+    /// Numbers separated by spaces or tabs, each with an optional sign, up to
+    /// the first thing that isn't one.
+    pub fn numbers<T>(&mut self) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut numbers = Vec::new();
+
+        loop {
+            self.spaces();
+            let mut chars = self.rest.chars();
+            let starts_number = match chars.next() {
+                Some('+' | '-') => chars.next().is_some_and(|c| c.is_ascii_digit()),
+                Some(c) => c.is_ascii_digit(),
+                None => false,
+            };
+
+            if !starts_number {
+                return Ok(numbers);
+            }
+            numbers.push(self.signed()?);
+        }
+    }
+
+    /// One or more `item`s with `separator` between them.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = vec![item(self)?];
+
+        while self.eat(separator) {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// The end of a line, `\n` or `\r\n`, or of the text.
+    pub fn newline(&mut self) -> Result<()> {
+        if self.is_empty() || self.eat("\n") || self.eat("\r\n") {
+            Ok(())
+        } else {
+            Err(self.error(format!("Unexpected {:?}", self.peek().unwrap())))
+        }
+    }
+
+    /// Fails unless only whitespace is left.
+    pub fn end(&mut self) -> Result<()> {
+        self.take_while(char::is_whitespace);
+
+        match self.peek() {
+            None => Ok(()),
+            Some(c) => Err(self.error(format!("Unexpected {:?}", c))),
+        }
+    }
+
+    /// Parses every remaining line with `line`, which must consume the whole line.
+    pub fn lines<T>(&mut self, mut line: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+
+        while !self.is_empty() {
+            items.push(line(self)?);
+            self.newline()?;
+        }
+
+        Ok(items)
+    }
+
+    /// Splits off the text up to the next blank line and skips past it.
+    pub fn section(&mut self) -> Option<Parser<'a>> {
+        self.take_while(|c| c == '\r' || c == '\n');
+
+        if self.is_empty() {
+            return None;
+        }
+
+        let len = self
+            .rest
+            .split_inclusive('\n')
+            .take_while(|line| !line.trim_end_matches(['\r', '\n']).is_empty())
+            .map(str::len)
+            .sum();
+        let section = self.clone();
+        let text = self.take(len);
+
+        Some(Parser {
+            rest: text,
+            loc: section.loc,
+        })
+    }
+
+    /// Like `section`, for a section the input can't do without.
+    pub fn expect_section(&mut self, name: &str) -> Result<Parser<'a>> {
+        self.section()
+            .ok_or_else(|| self.error(format!("Expected the {}", name)))
+    }
+
+    fn take(&mut self, len: usize) -> &'a str {
+        let (taken, _) = self.rest.split_at(len);
+
+        for _ in taken.chars() {
+            self.consume();
+        }
+
+        taken
+    }
+}
+
+fn parse_token<T>(start: &Parser, token: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    if token.trim_start_matches(['+', '-']).is_empty() {
+        return Err(start.error("Expected a number"));
+    }
+
+    token
+        .parse()
+        .map_err(|e| start.error(format!("Invalid number {:?} ({})", token, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_fields_and_lists() {
+        let mut parser = Parser::new("Register A: -729\nProgram: 0,1,5\n");
+
+        assert_eq!(parser.field::<i64>("Register A:").unwrap(), -729);
+        parser.newline().unwrap();
+        parser.literal("Program: ").unwrap();
+        let program = parser.separated(",", |p| p.number::<u8>()).unwrap();
+        assert_eq!(program, [0, 1, 5]);
+        parser.end().unwrap();
+    }
+
+    #[test]
+    fn splits_sections_keeping_locations() {
+        let mut parser = Parser::new("ab\ncd\n\n1|2\n");
+
+        let mut map = parser.section().unwrap();
+        assert_eq!(map.line(), "ab");
+        map.newline().unwrap();
+        assert_eq!(map.line(), "cd");
+        map.end().unwrap();
+
+        let mut rules = parser.section().unwrap();
+        assert_eq!(rules.loc(), Location { line: 3, column: 0 });
+        rules.number::<u32>().unwrap();
+        let error = rules.literal(",").unwrap_err();
+        assert_eq!(error.to_string(), "Expected \",\" at line 4, column 2");
+
+        assert!(parser.section().is_none());
+    }
+
+    #[test]
+    fn accepts_crlf_line_endings() {
+        let mut parser = Parser::new("ab\r\ncd\r\n\r\n1|2\r\n");
+
+        let mut map = parser.section().unwrap();
+        assert_eq!(map.lines(|p| Ok(p.line())).unwrap(), ["ab", "cd"]);
+
+        let mut rules = parser.section().unwrap();
+        assert_eq!(rules.loc(), Location { line: 3, column: 0 });
+        assert_eq!(rules.separated("|", |p| p.number::<u8>()).unwrap(), [1, 2]);
+        rules.newline().unwrap();
+        rules.end().unwrap();
+
+        assert!(parser.section().is_none());
+    }
+
+    #[test]
+    fn reads_runs_of_numbers() {
+        let mut parser = Parser::new("  41 -48\t+83 | 8");
+
+        assert_eq!(parser.numbers::<i32>().unwrap(), [41, -48, 83]);
+        assert_eq!(parser.line(), "| 8");
+        assert!(Parser::new("|").numbers::<u8>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn reports_bad_numbers() {
+        let error = Parser::new("x").number::<u32>().unwrap_err();
+        assert_eq!(error.to_string(), "Expected a number at line 1, column 1");
+
+        let error = Parser::new("7\n300\n")
+            .lines(|p| p.number::<u8>())
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid number \"300\" (number too large to fit in target type) at line 2, column 1"
+        );
+    }
+}
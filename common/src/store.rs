@@ -0,0 +1,201 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+
+const ENDPOINT: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/AdamHerrmann/adventofcode input store";
+
+/// Puzzle inputs cached on disk as `<dir>/<year>/day-<dd>/input.txt`, downloaded
+/// once with a session token when missing.
+///
+/// The default directory is the workspace root, so inputs sit next to each
+/// day's crate. `AOC_INPUTS`, `AOC_SESSION` and `AOC_ENDPOINT` override the
+/// directory, token and server.
+#[derive(Clone, Debug)]
+pub struct InputStore {
+    dir: PathBuf,
+    endpoint: String,
+    session: Option<String>,
+}
+
+impl InputStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            endpoint: ENDPOINT.to_string(),
+            session: None,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let dir = env::var_os("AOC_INPUTS").map_or_else(workspace_root, PathBuf::from);
+        let mut store = Self::new(dir);
+
+        if let Ok(endpoint) = env::var("AOC_ENDPOINT") {
+            store = store.with_endpoint(endpoint);
+        }
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            store = store.with_session(session.trim());
+        }
+
+        store
+    }
+
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_session(mut self, session: impl Into<String>) -> Self {
+        self.session = Some(session.into());
+        self
+    }
+
+    /// Where the input for `year` and `day` is, or would be, cached.
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day-{:02}", day))
+            .join("input.txt")
+    }
+
+    /// The cached input's path, downloading it first if it isn't cached yet.
+    pub fn get(&self, year: u16, day: u8) -> Result<PathBuf> {
+        let path = self.path(year, day);
+
+        if !path.exists() {
+            let contents = self.fetch(year, day)?;
+            write(&path, &contents)?;
+        }
+
+        Ok(path)
+    }
+
+    fn fetch(&self, year: u16, day: u8) -> Result<String> {
+        let Some(session) = &self.session else {
+            bail!(
+                "No input for {} day {} at {}, set AOC_SESSION to download it",
+                year,
+                day,
+                self.path(year, day).display()
+            );
+        };
+
+        let url = format!("{}/{}/day/{}/input", self.endpoint, year, day);
+
+        ureq::get(&url)
+            .header("Cookie", &format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .with_context(|| format!("Failed to download {}", url))
+    }
+}
+
+/// Writes through a temporary file so an interrupted download never looks cached.
+fn write(path: &Path, contents: &str) -> Result<()> {
+    let dir = path.parent().expect("input paths have a parent");
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    let partial = path.with_extension("partial");
+    fs::write(&partial, contents)
+        .and_then(|_| fs::rename(&partial, path))
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("common crate lives inside the workspace")
+        .to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    /// Serves `/2024/day/5/input` to requests carrying the `secret` session,
+    /// counting every request it sees.
+    fn serve() -> (String, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let authorized = request
+                    .headers()
+                    .iter()
+                    .any(|h| h.field.equiv("Cookie") && h.value == "session=secret");
+
+                let response = match (request.url(), authorized) {
+                    ("/2024/day/5/input", true) => Response::from_string("47|53\n"),
+                    (_, false) => Response::from_string("unauthorized").with_status_code(400),
+                    _ => Response::from_string("not found").with_status_code(404),
+                };
+                request.respond(response).unwrap();
+            }
+        });
+
+        (endpoint, requests)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-store-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once_then_reads_the_cache() {
+        let (endpoint, requests) = serve();
+        let dir = temp_dir("cache");
+        let store = InputStore::new(&dir)
+            .with_endpoint(endpoint)
+            .with_session("secret");
+
+        let path = store.get(2024, 5).unwrap();
+        assert_eq!(path, dir.join("2024/day-05/input.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "47|53\n");
+
+        store.get(2024, 5).unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn failed_downloads_are_not_cached() {
+        let (endpoint, requests) = serve();
+        let dir = temp_dir("failed");
+        let store = InputStore::new(&dir).with_endpoint(endpoint);
+
+        let error = store.with_session("wrong").get(2024, 5).unwrap_err();
+        assert!(error.to_string().starts_with("Failed to download"));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert!(!dir.join("2024/day-05/input.txt").exists());
+
+        // nothing should be there, but clear up if a regression wrote something
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn missing_session_is_reported() {
+        let dir = temp_dir("session");
+        let error = InputStore::new(&dir).get(2024, 5).unwrap_err();
+
+        assert!(error.to_string().contains("set AOC_SESSION"));
+    }
+}
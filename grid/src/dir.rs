@@ -0,0 +1,85 @@
+/// A compass direction on a grid where north is towards row 0.
+///
+/// The four cardinal directions come first, so `index` can address
+/// per-direction arrays of length 4 when diagonals are not in play.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    North,
+    East,
+    South,
+    West,
+    NorthEast,
+    SouthEast,
+    SouthWest,
+    NorthWest,
+}
+
+impl Dir {
+    /// The 4-neighbourhood, clockwise from north.
+    pub const CARDINAL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    /// The 8-neighbourhood, clockwise from north.
+    pub const ALL: [Dir; 8] = [
+        Dir::North,
+        Dir::NorthEast,
+        Dir::East,
+        Dir::SouthEast,
+        Dir::South,
+        Dir::SouthWest,
+        Dir::West,
+        Dir::NorthWest,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The `(row, column)` offset of a single step.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Dir::North => (-1, 0),
+            Dir::East => (0, 1),
+            Dir::South => (1, 0),
+            Dir::West => (0, -1),
+            Dir::NorthEast => (-1, 1),
+            Dir::SouthEast => (1, 1),
+            Dir::SouthWest => (1, -1),
+            Dir::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Rotates 90 degrees clockwise.
+    pub fn turn_right(self) -> Dir {
+        match self {
+            Dir::North => Dir::East,
+            Dir::East => Dir::South,
+            Dir::South => Dir::West,
+            Dir::West => Dir::North,
+            Dir::NorthEast => Dir::SouthEast,
+            Dir::SouthEast => Dir::SouthWest,
+            Dir::SouthWest => Dir::NorthWest,
+            Dir::NorthWest => Dir::NorthEast,
+        }
+    }
+
+    /// Rotates 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Dir {
+        self.opposite().turn_right()
+    }
+
+    pub fn opposite(self) -> Dir {
+        self.turn_right().turn_right()
+    }
+
+    /// Rotates 45 degrees clockwise, so cardinal directions turn diagonal.
+    pub fn turn_right_45(self) -> Dir {
+        let index = Dir::ALL.iter().position(|&dir| dir == self).unwrap();
+        Dir::ALL[(index + 1) % 8]
+    }
+
+    /// Rotates 45 degrees counter-clockwise.
+    pub fn turn_left_45(self) -> Dir {
+        let index = Dir::ALL.iter().position(|&dir| dir == self).unwrap();
+        Dir::ALL[(index + 7) % 8]
+    }
+}
@@ -0,0 +1,275 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Error, Result};
+
+use crate::{Dir, Pos};
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Wraps cells already laid out row by row.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "cells don't fill the grid");
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses puzzle text one character at a time, one row per line.
+    pub fn parse_with(input: &str, mut parse: impl FnMut(Pos, char) -> Result<T>) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (row, line) in input.lines().enumerate() {
+            let line_width = line.chars().count();
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => bail!(
+                    "Line {} has {} columns, expected {}",
+                    row + 1,
+                    line_width,
+                    width
+                ),
+                Some(_) => {}
+            }
+
+            for (col, c) in line.chars().enumerate() {
+                let cell = parse(Pos::new(row, col), c).with_context(|| {
+                    format!(
+                        "Invalid cell {:?} at line {}, column {}",
+                        c,
+                        row + 1,
+                        col + 1
+                    )
+                })?;
+                cells.push(cell);
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.offset(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            let offset = self.offset(pos);
+            Some(&mut self.cells[offset])
+        } else {
+            None
+        }
+    }
+
+    /// The neighbouring position in `dir`, or `None` if it falls off the grid.
+    pub fn step(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        pos.step(dir).filter(|&next| self.contains(next))
+    }
+
+    /// The in-bounds members of the 4-neighbourhood of `pos`.
+    pub fn neighbors(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::CARDINAL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// The in-bounds members of the 8-neighbourhood of `pos`.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos::new(row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// Walks from `from` (inclusive) in `dir` until the edge of the grid.
+    /// With a diagonal `dir` this slices out a diagonal.
+    pub fn ray(&self, from: Pos, dir: Dir) -> impl Iterator<Item = (Pos, &T)> {
+        std::iter::successors(self.contains(from).then_some(from), move |&pos| {
+            self.step(pos, dir)
+        })
+        .map(|pos| (pos, &self[pos]))
+    }
+
+    /// The first position, in row-major order, whose cell matches.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn offset(&self, pos: Pos) -> usize {
+        pos.row * self.width + pos.col
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{:?} out of bounds", pos);
+        &self.cells[self.offset(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{:?} out of bounds", pos);
+        let offset = self.offset(pos);
+        &mut self.cells[offset]
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: std::error::Error + Send + Sync + 'static,
+{
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Self::parse_with(input, |_, c| Ok(T::try_from(c)?))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "abc\ndef\n";
+
+    #[test]
+    fn round_trips_text() {
+        let grid: Grid<char> = TEXT.parse().unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Pos::new(1, 2)], 'f');
+        assert_eq!(grid.to_string(), TEXT);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let error = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+
+        assert_eq!(error.to_string(), "Line 2 has 2 columns, expected 3");
+    }
+
+    #[test]
+    fn reports_cell_location() {
+        let error =
+            Grid::parse_with("12\n3x\n", |_, c| c.to_digit(10).context("not a digit")).unwrap_err();
+
+        assert_eq!(error.to_string(), "Invalid cell 'x' at line 2, column 2");
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid: Grid<char> = TEXT.parse().unwrap();
+
+        let corner: Vec<_> = grid.neighbors(Pos::new(0, 0)).collect();
+        assert_eq!(corner, [Pos::new(0, 1), Pos::new(1, 0)]);
+        assert_eq!(grid.neighbors8(Pos::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn slices_rows_columns_and_diagonals() {
+        let grid: Grid<char> = TEXT.parse().unwrap();
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert!(grid.column(1).copied().eq(['b', 'e']));
+        assert!(grid
+            .ray(Pos::new(0, 0), Dir::SouthEast)
+            .map(|(_, &c)| c)
+            .eq(['a', 'e']));
+        assert!(grid
+            .ray(Pos::new(1, 2), Dir::West)
+            .map(|(_, &c)| c)
+            .eq(['f', 'e', 'd']));
+    }
+}
@@ -0,0 +1,156 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::{Grid, Rgb};
+
+impl Grid<Rgb> {
+    /// Every cell as a `scale` by `scale` block of pixels, row by row.
+    fn pixels(&self, scale: usize) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.width() * self.height() * scale * scale * 3);
+
+        for row in self.rows() {
+            for _ in 0..scale {
+                for cell in row {
+                    for _ in 0..scale {
+                        pixels.extend(cell);
+                    }
+                }
+            }
+        }
+
+        pixels
+    }
+
+    /// Writes a binary PPM (`P6`) image.
+    pub fn write_ppm(&self, scale: usize, mut writer: impl Write) -> Result<()> {
+        write!(
+            writer,
+            "P6\n{} {}\n255\n",
+            self.width() * scale,
+            self.height() * scale
+        )?;
+        writer.write_all(&self.pixels(scale))?;
+        Ok(())
+    }
+
+    pub fn write_png(&self, scale: usize, writer: impl Write) -> Result<()> {
+        let mut encoder = png::Encoder::new(
+            writer,
+            (self.width() * scale).try_into()?,
+            (self.height() * scale).try_into()?,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()?
+            .write_image_data(&self.pixels(scale))?;
+        Ok(())
+    }
+
+    /// Writes a PPM or PNG, going by the extension of `path`.
+    pub fn save(&self, path: impl AsRef<Path>, scale: usize) -> Result<()> {
+        let path = path.as_ref();
+        let writer = || -> Result<_> { Ok(BufWriter::new(File::create(path)?)) };
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("ppm") => self.write_ppm(scale, writer()?),
+            Some("png") => self.write_png(scale, writer()?),
+            _ => bail!("Unknown image format {:?}, expected .ppm or .png", path),
+        }
+    }
+}
+
+/// Writes an animated GIF that loops forever, showing each frame for `delay`
+/// hundredths of a second. Frames must all be the same size, and each may use
+/// at most 256 colours.
+pub fn write_gif(
+    frames: impl IntoIterator<Item = Grid<Rgb>>,
+    scale: usize,
+    delay: u16,
+    writer: impl Write,
+) -> Result<()> {
+    let mut frames = frames.into_iter().peekable();
+    let Some(first) = frames.peek() else {
+        bail!("No frames to write");
+    };
+    let size = (first.width(), first.height());
+    let width = (size.0 * scale).try_into()?;
+    let height = (size.1 * scale).try_into()?;
+
+    let mut encoder = gif::Encoder::new(writer, width, height, &[])?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    for grid in frames {
+        if (grid.width(), grid.height()) != size {
+            bail!(
+                "Frame is {}x{}, expected {}x{}",
+                grid.width(),
+                grid.height(),
+                size.0,
+                size.1
+            );
+        }
+
+        let mut palette: Vec<Rgb> = Vec::new();
+        let indices = grid
+            .pixels(scale)
+            .chunks(3)
+            .map(|pixel| {
+                let index = match palette.iter().position(|colour| colour == pixel) {
+                    Some(index) => index,
+                    None => {
+                        palette.push([pixel[0], pixel[1], pixel[2]]);
+                        palette.len() - 1
+                    }
+                };
+                u8::try_from(index).map_err(|_| anyhow!("More than 256 colours in a frame"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut frame =
+            gif::Frame::from_palette_pixels(width, height, indices, palette.concat(), None);
+        frame.delay = delay;
+        encoder.write_frame(&frame)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_scaled_ppm() -> Result<()> {
+        let grid = Grid::from_vec(2, 1, vec![[255, 0, 0], [0, 0, 255]]);
+        let mut bytes = Vec::new();
+        grid.write_ppm(2, &mut bytes)?;
+
+        let red = [255, 0, 0];
+        let blue = [0, 0, 255];
+        let row = [red, red, blue, blue].concat();
+        assert_eq!(bytes, [b"P6\n4 2\n255\n".as_slice(), &row, &row].concat());
+        Ok(())
+    }
+
+    #[test]
+    fn writes_animated_gifs() -> Result<()> {
+        let frames = [[255, 0, 0], [0, 0, 255]].map(|colour| Grid::new(3, 2, colour));
+        let mut bytes = Vec::new();
+        write_gif(frames.clone(), 1, 10, &mut bytes)?;
+
+        assert!(bytes.starts_with(b"GIF89a"));
+        assert!(write_gif([], 1, 10, Vec::new()).is_err());
+        assert!(write_gif(
+            [frames[0].clone(), Grid::new(1, 1, [0; 3])],
+            1,
+            10,
+            Vec::new()
+        )
+        .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+mod dir;
+mod grid;
+#[cfg(feature = "image")]
+mod image;
+mod pos;
+
+pub use dir::Dir;
+pub use grid::Grid;
+#[cfg(feature = "image")]
+pub use image::write_gif;
+pub use pos::Pos;
+
+/// A pixel colour, red, green then blue.
+pub type Rgb = [u8; 3];
@@ -0,0 +1,29 @@
+use crate::Dir;
+
+/// A cell position, counted from the top-left corner.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// The neighbouring position in `dir`, or `None` if that would leave the first quadrant.
+    /// Use `Grid::step` to also check the far edges.
+    pub fn step(self, dir: Dir) -> Option<Pos> {
+        let (row, col) = dir.delta();
+
+        Some(Pos {
+            row: self.row.checked_add_signed(row)?,
+            col: self.col.checked_add_signed(col)?,
+        })
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
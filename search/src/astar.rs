@@ -0,0 +1,67 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Add;
+
+use keyed_priority_queue::KeyedPriorityQueue;
+
+use crate::{unwind, Path};
+
+/// Cheapest path to a goal. Edge costs must not be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Cheapest path to a goal, exploring in order of cost so far plus `heuristic`.
+/// The heuristic must never overestimate the remaining cost.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    let mut queue = KeyedPriorityQueue::new();
+    queue.push(start.clone(), Reverse(heuristic(&start)));
+
+    while let Some((state, _)) = queue.pop() {
+        let cost = costs[&state];
+
+        if is_goal(&state) {
+            return Some(Path {
+                cost,
+                states: unwind(&parents, state),
+            });
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+
+            if costs.get(&next).is_some_and(|&known| known <= next_cost) {
+                continue;
+            }
+
+            let estimate = next_cost + heuristic(&next);
+            costs.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), Some(state.clone()));
+            queue.push(next, Reverse(estimate));
+        }
+    }
+
+    None
+}
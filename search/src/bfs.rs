@@ -0,0 +1,37 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::{unwind, Path};
+
+/// Breadth-first search where every edge costs one.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            let states = unwind(&parents, state);
+            return Some(Path {
+                cost: states.len() - 1,
+                states,
+            });
+        }
+
+        for next in successors(&state) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(state.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
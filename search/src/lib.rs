@@ -0,0 +1,92 @@
+//! Graph searches over an implicit graph: a start state plus a function
+//! giving the successors of each state.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+mod astar;
+mod bfs;
+mod optimal;
+
+pub use astar::{astar, dijkstra};
+pub use bfs::bfs;
+pub use optimal::{all_optimal, AllOptimal};
+
+/// A single best path from the start to a goal, both ends included.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Follows parent links back from `goal` to the start.
+fn unwind<S: Clone + Eq + Hash>(parents: &HashMap<S, Option<S>>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+
+    while let Some(Some(parent)) = parents.get(states.last().unwrap()) {
+        states.push(parent.clone());
+    }
+
+    states.reverse();
+    states
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1-> 1 -1-> 3
+    // 0 -1-> 2 -1-> 3
+    // 0 -----5----> 3
+    fn successors(&state: &u8) -> Vec<(u8, u32)> {
+        match state {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 | 2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_counts_edges() {
+        let path = bfs(
+            0,
+            |&s| successors(&s).into_iter().map(|(n, _)| n),
+            |&s| s == 3,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.states, [0, 3]);
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        let path = dijkstra(0, successors, |&s| s == 3).unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.states, [0, 1, 3]);
+
+        let path = astar(0, successors, |&s| if s == 3 { 0 } else { 1 }, |&s| s == 3).unwrap();
+        assert_eq!(path.cost, 2);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        assert_eq!(dijkstra(1, successors, |&s| s == 0), None);
+    }
+
+    #[test]
+    fn all_optimal_keeps_every_tie() {
+        let optimal = all_optimal(0, successors, |&s| s == 3).unwrap();
+
+        assert_eq!(optimal.cost, 2);
+        assert_eq!(optimal.goals, [3]);
+
+        let mut predecessors = optimal.predecessors(&3).to_vec();
+        predecessors.sort();
+        assert_eq!(predecessors, [1, 2]);
+
+        let mut states: Vec<_> = optimal.states().into_iter().collect();
+        states.sort();
+        assert_eq!(states, [0, 1, 2, 3]);
+    }
+}
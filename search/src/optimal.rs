@@ -0,0 +1,93 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+use keyed_priority_queue::KeyedPriorityQueue;
+
+/// Every optimal path to the cheapest goals, kept as a DAG of predecessors.
+#[derive(Clone, Debug)]
+pub struct AllOptimal<S, C> {
+    pub cost: C,
+    /// Every goal state reachable at `cost`.
+    pub goals: Vec<S>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C> AllOptimal<S, C> {
+    /// The states an optimal path can arrive at `state` from.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// Every state that lies on at least one optimal path.
+    pub fn states(&self) -> HashSet<S> {
+        let mut seen: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut stack = self.goals.clone();
+
+        while let Some(state) = stack.pop() {
+            for previous in self.predecessors(&state) {
+                if seen.insert(previous.clone()) {
+                    stack.push(previous.clone());
+                }
+            }
+        }
+
+        seen
+    }
+}
+
+/// Dijkstra that records every equally cheap way into each state rather than
+/// just the first, and keeps going until all goals at the optimal cost are found.
+pub fn all_optimal<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<AllOptimal<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut queue = KeyedPriorityQueue::new();
+    queue.push(start, Reverse(C::default()));
+
+    let mut best = None;
+    let mut goals = Vec::new();
+
+    while let Some((state, Reverse(cost))) = queue.pop() {
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+
+        if is_goal(&state) {
+            best = Some(cost);
+            goals.push(state);
+            continue;
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+
+            match costs.get(&next) {
+                Some(&known) if known < next_cost => {}
+                Some(&known) if known == next_cost => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    costs.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push(next, Reverse(next_cost));
+                }
+            }
+        }
+    }
+
+    Some(AllOptimal {
+        cost: best?,
+        goals,
+        predecessors,
+    })
+}